@@ -0,0 +1,194 @@
+use crate::{
+    endian::sub_slice,
+    header::{
+        ident::{
+            ElfClass,
+            ElfEndian,
+            ElfIdent,
+        },
+        read_class_dependent,
+        SectionFlags,
+        SectionHeader,
+    },
+    Elf,
+    Error,
+};
+use alloc::borrow::Cow;
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+/// This enum represents the compression algorithm of a compressed section. The decompression of
+/// zlib and zstd is only available with the `zlib` and `zstd` features.
+///
+/// ## See also
+/// - [Compressed Sections](https://www.sco.com/developers/gabi/latest/ch4.sheader.html#compression_header)
+///   by SCO, Inc.
+#[repr(u32)]
+#[rustfmt::skip]
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum CompressionType {
+    Zlib         = 1,
+    Zstd         = 2,
+    Unknown(u32) = 3
+}
+
+impl From<u32> for CompressionType {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Zlib,
+            2 => Self::Zstd,
+            value => Self::Unknown(value),
+        }
+    }
+}
+
+/// This structure represents the compression header (`Elf32_Chdr` or `Elf64_Chdr`) at the start
+/// of the data of sections with the [SectionFlags::COMPRESSED] flag.
+///
+/// ## See also
+/// - [Compressed Sections](https://www.sco.com/developers/gabi/latest/ch4.sheader.html#compression_header)
+///   by SCO, Inc.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct CompressionHeader {
+    /// This field indicates the compression algorithm of the section data.
+    pub ty: CompressionType,
+
+    /// This field indicates the size of the uncompressed section data.
+    pub size: u64,
+
+    /// This field indicates the alignment of the uncompressed section data.
+    pub alignment: u64,
+}
+
+impl CompressionHeader {
+    /// This function returns the size of the compression header for ELF files with the specified
+    /// class.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass) -> Result<usize, Error> {
+        match class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => Err(Error::InvalidClass),
+            ElfClass::Class32 => Ok(12),
+            ElfClass::Class64 => Ok(24),
+        }
+    }
+
+    /// This function reads the data from the slice (with offset) and parses it into a
+    /// [CompressionHeader] structure for the ELF file.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the header could be read completely
+    pub fn read(ident: &ElfIdent, slice: &[u8], mut offset: usize) -> Result<Self, Error> {
+        sub_slice(slice, offset, Self::size(ident.class)?, "CompressionHeader")?;
+        let ty = CompressionType::from(ident.endian.read::<u32>(slice, Some(&mut offset))?);
+
+        // The 64-bit header has a reserved field after the type
+        if ident.class == ElfClass::Class64 {
+            offset += 4;
+        }
+
+        let size = read_class_dependent!(ident, slice, &mut offset);
+        let alignment = read_class_dependent!(ident, slice, &mut offset);
+        Ok(Self {
+            ty,
+            size,
+            alignment,
+        })
+    }
+}
+
+/// This function decompresses the specified data with the specified algorithm. The size of the
+/// decompressed data must be equal to the specified size.
+#[cfg_attr(not(any(feature = "zlib", feature = "zstd")), allow(unused_variables))]
+fn decompress(ty: CompressionType, data: &[u8], size: usize) -> Result<Vec<u8>, Error> {
+    match ty {
+        #[cfg(feature = "zlib")]
+        CompressionType::Zlib => {
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, size)
+                .ok()
+                .filter(|decompressed| decompressed.len() == size)
+                .ok_or(Error::InvalidCompressedData)
+        }
+        #[cfg(feature = "zstd")]
+        CompressionType::Zstd => {
+            use ruzstd::io::Read;
+
+            // Grow the output while decoding, so the size from the header isn't allocated upfront
+            let mut decoder =
+                ruzstd::StreamingDecoder::new(data).map_err(|_| Error::InvalidCompressedData)?;
+            let mut decompressed = Vec::new();
+            let mut chunk = [0; 4096];
+            loop {
+                match decoder.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(length) if decompressed.len() + length <= size => {
+                        decompressed.extend_from_slice(&chunk[..length])
+                    }
+                    _ => return Err(Error::InvalidCompressedData),
+                }
+            }
+            if decompressed.len() != size {
+                return Err(Error::InvalidCompressedData);
+            }
+            Ok(decompressed)
+        }
+        ty => Err(Error::UnsupportedCompression(ty)),
+    }
+}
+
+impl SectionHeader {
+    /// This function reads the compression header of this section. If the section doesn't have
+    /// the [SectionFlags::COMPRESSED] flag, this function returns None.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the section is not in the ELF data
+    /// - For more errors, see [CompressionHeader::read]
+    pub fn compression_header(&self, elf: &Elf) -> Result<Option<CompressionHeader>, Error> {
+        if !self.flags.contains(SectionFlags::COMPRESSED) {
+            return Ok(None);
+        }
+        Ok(Some(CompressionHeader::read(
+            &elf.header.ident,
+            self.data(elf)?,
+            0,
+        )?))
+    }
+
+    /// This function returns the decompressed data of this section. Sections with the
+    /// [SectionFlags::COMPRESSED] flag and legacy `.zdebug` sections, which start with `ZLIB` and
+    /// the big-endian size of the decompressed data, are decompressed. The data of other sections
+    /// is returned without copy.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnsupportedCompression] - The algorithm is unknown or the feature is disabled
+    /// - [Error::InvalidCompressedData] - The compressed data can't be decompressed
+    /// - For errors while reading the compression header, see [SectionHeader::compression_header]
+    pub fn decompressed_data<'a>(&self, elf: &Elf<'a>) -> Result<Cow<'a, [u8]>, Error> {
+        let data = self.data(elf)?;
+        if let Some(header) = self.compression_header(elf)? {
+            let offset = CompressionHeader::size(elf.header.ident.class)?;
+            return Ok(Cow::Owned(decompress(
+                header.ty,
+                &data[offset..],
+                header.size as usize,
+            )?));
+        }
+
+        // Legacy compressed sections (like `.zdebug_info`) have a header with the magic and size
+        let legacy = elf
+            .section_name(self)
+            .map_or(false, |name| name.starts_with(".zdebug"));
+        if legacy && data.starts_with(b"ZLIB") {
+            let size = ElfEndian::Big.read::<u64>(data, Some(&mut 4))?;
+            return Ok(Cow::Owned(decompress(
+                CompressionType::Zlib,
+                &data[12..],
+                size as usize,
+            )?));
+        }
+        Ok(Cow::Borrowed(data))
+    }
+}
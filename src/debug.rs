@@ -0,0 +1,201 @@
+use crate::{
+    note::{
+        NoteIter,
+        NoteKind,
+    },
+    std::str,
+    Elf,
+    Error,
+};
+#[cfg(feature = "std")]
+use std::{
+    fmt::Write,
+    fs::File,
+    io::Read,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// This structure represents the content of the `.gnu_debuglink` section. The section contains the
+/// file name of the separate debug file and the CRC32 checksum of the debug file.
+///
+/// ## See also
+/// - [Separate Debug Files](https://sourceware.org/gdb/current/onlinedocs/gdb.html/Separate-Debug-Files.html)
+///   by the GDB project
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct DebugLink<'a> {
+    /// This field indicates the name of the separate debug file without directory.
+    pub file_name: &'a str,
+
+    /// This field indicates the CRC32 checksum of the whole separate debug file.
+    pub crc: u32,
+}
+
+/// This function calculates the CRC32 checksum (as used by `.gnu_debuglink`) over the specified
+/// data. The checksum can be calculated incrementally by passing the last checksum as start value.
+/// The start value for new checksums is zero.
+pub fn crc32(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB88320 & 0_u32.wrapping_sub(crc & 1));
+        }
+    }
+    !crc
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns the unique build ID of the ELF file. The ID is read from the
+    /// `NT_GNU_BUILD_ID` note in the note segments or the `.note.gnu.build-id` section. If there
+    /// is no build ID, this function returns None.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of a segment or section is not in the ELF data
+    /// - [Error::UnexpectedEnd] - A note ends outside of the segment or section
+    /// - [Error::InvalidString] - The name of a note is not valid UTF-8
+    pub fn build_id(&self) -> Result<Option<&'a [u8]>, Error> {
+        let mut notes = self.notes()?;
+        if let Some(section_header) = self.section_by_name(".note.gnu.build-id") {
+            for note in NoteIter::from_section(self, section_header)? {
+                notes.push(note?);
+            }
+        }
+
+        for note in notes {
+            if let NoteKind::GnuBuildId(build_id) = note.kind()? {
+                return Ok(Some(build_id));
+            }
+        }
+        Ok(None)
+    }
+
+    /// This function returns the content of the `.gnu_debuglink` section, which references the
+    /// separate debug file. If there is no such section, this function returns None.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the section is not in the ELF data
+    /// - [Error::UnexpectedEnd] - The file name or checksum ends outside of the section
+    /// - [Error::InvalidString] - The file name is not valid UTF-8
+    pub fn debug_link(&self) -> Result<Option<DebugLink<'a>>, Error> {
+        let Some(section_header) = self.section_by_name(".gnu_debuglink") else {
+            return Ok(None);
+        };
+
+        // Read file name and the checksum, which is aligned to 4 bytes after the name
+        let data = section_header.data(self)?;
+        let length = data
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or(Error::UnexpectedEnd(".gnu_debuglink", 0))?;
+        let file_name = str::from_utf8(&data[..length]).map_err(|_| Error::InvalidString(0))?;
+        let crc = self
+            .header
+            .ident
+            .endian
+            .read::<u32>(data, Some(&mut ((length + 4) & !3)))?;
+        Ok(Some(DebugLink { file_name, crc }))
+    }
+}
+
+/// This structure locates the separate debug files of ELF files in a local directory tree. The
+/// debug file is searched by the build ID in the `.build-id/xx/yyyy.debug` layout of the root
+/// directory and by the `.gnu_debuglink` section next to the ELF file, in the `.debug` directory
+/// next to the ELF file and in the root directory. (Only available with `std`-feature)
+///
+/// ## See also
+/// - [Separate Debug Files](https://sourceware.org/gdb/current/onlinedocs/gdb.html/Separate-Debug-Files.html)
+///   by the GDB project
+#[cfg(feature = "std")]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct DebugFileResolver {
+    root: PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl Default for DebugFileResolver {
+    fn default() -> Self {
+        Self::new("/usr/lib/debug")
+    }
+}
+
+#[cfg(feature = "std")]
+impl DebugFileResolver {
+    /// This function creates a resolver, which searches the debug files in the specified root
+    /// directory (like `/usr/lib/debug`).
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// This function returns the path of the debug file with the specified build ID in the
+    /// `.build-id` directory of the root directory. The first byte of the build ID is the name of
+    /// the sub-directory, the other bytes are the name of the file.
+    pub fn build_id_path(&self, build_id: &[u8]) -> PathBuf {
+        let mut path = self.root.join(".build-id");
+        if let Some((first, rest)) = build_id.split_first() {
+            path.push(format!("{:02x}", first));
+            let mut file_name = String::new();
+            for byte in rest {
+                let _ = write!(file_name, "{:02x}", byte);
+            }
+            file_name.push_str(".debug");
+            path.push(file_name);
+        }
+        path
+    }
+
+    /// This function locates the separate debug file of the specified ELF file, which is located at
+    /// the specified path. The debug file is searched by the build ID first. After that, the debug
+    /// link is used and the CRC32 checksum of the candidates is validated. If no debug file is
+    /// found, this function returns None.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::IO] - A candidate for the debug file can't be read
+    /// - For errors while reading the build ID or debug link, see [Elf::build_id] and
+    ///   [Elf::debug_link]
+    pub fn resolve(&self, elf: &Elf, path: &Path) -> Result<Option<PathBuf>, Error> {
+        if let Some(build_id) = elf.build_id()? {
+            let debug_path = self.build_id_path(build_id);
+            if debug_path.is_file() {
+                return Ok(Some(debug_path));
+            }
+        }
+
+        let Some(debug_link) = elf.debug_link()? else {
+            return Ok(None);
+        };
+        let directory = path.parent().unwrap_or(Path::new(""));
+        let candidates = [
+            directory.join(debug_link.file_name),
+            directory.join(".debug").join(debug_link.file_name),
+            self.root
+                .join(directory.strip_prefix("/").unwrap_or(directory))
+                .join(debug_link.file_name),
+        ];
+        for candidate in candidates {
+            if candidate != path
+                && candidate.is_file()
+                && Self::file_crc32(&candidate)? == debug_link.crc
+            {
+                return Ok(Some(candidate));
+            }
+        }
+        Ok(None)
+    }
+
+    /// This function calculates the CRC32 checksum of the file at the specified path without
+    /// reading the whole file into the memory.
+    fn file_crc32(path: &Path) -> Result<u32, Error> {
+        let mut file = File::open(path)?;
+        let mut buffer = [0; 8192];
+        let mut crc = 0;
+        loop {
+            match file.read(&mut buffer)? {
+                0 => return Ok(crc),
+                length => crc = crc32(crc, &buffer[..length]),
+            }
+        }
+    }
+}
@@ -0,0 +1,621 @@
+use crate::{
+    endian::sub_slice,
+    header::{
+        ident::{
+            ElfClass,
+            ElfIdent,
+        },
+        read_class_dependent,
+        SectionType,
+        SegmentType,
+    },
+    relocation::RelocationTable,
+    string_table::StringTable,
+    symbol::{
+        Symbol,
+        SymbolTable,
+    },
+    Elf,
+    Error,
+};
+use bitflags::bitflags;
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+bitflags! {
+    /// This structure contains the flags of the `DT_FLAGS` entry in the dynamic section.
+    ///
+    /// ## See also
+    /// - [Dynamic Section](https://www.sco.com/developers/gabi/latest/ch5.dynamic.html) by SCO, Inc.
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct DynamicFlags: u64 {
+        /// The object may reference the `$ORIGIN` substitution string
+        const ORIGIN     = 0x1;
+
+        /// The symbol resolution starts with the object itself
+        const SYMBOLIC   = 0x2;
+
+        /// Relocation entries may modify a non-writable segment
+        const TEXTREL    = 0x4;
+
+        /// All relocations must be processed before control is transferred to the program
+        const BIND_NOW   = 0x8;
+
+        /// The object uses the static thread-local storage model
+        const STATIC_TLS = 0x10;
+    }
+}
+
+bitflags! {
+    /// This structure contains the flags of the `DT_FLAGS_1` entry in the dynamic section. These
+    /// flags are an extension of the GNU toolchain.
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct DynamicFlags1: u64 {
+        /// All relocations must be processed before control is transferred to the program
+        const NOW        = 0x1;
+
+        /// The symbols of the object are made available for the symbol resolution
+        const GLOBAL     = 0x2;
+
+        /// The object is a member of a group
+        const GROUP      = 0x4;
+
+        /// The object can't be unloaded
+        const NODELETE   = 0x8;
+
+        /// The filtees of the object are loaded immediately
+        const LOADFLTR   = 0x10;
+
+        /// The object is initialized before all other objects
+        const INITFIRST  = 0x20;
+
+        /// The object can't be loaded with `dlopen`
+        const NOOPEN     = 0x40;
+
+        /// The object may reference the `$ORIGIN` substitution string
+        const ORIGIN     = 0x80;
+
+        /// The object uses direct binding
+        const DIRECT     = 0x100;
+
+        /// The object interposes all other objects except the executable
+        const INTERPOSE  = 0x400;
+
+        /// The default library search path is ignored for the dependencies of the object
+        const NODEFLIB   = 0x800;
+
+        /// The object can't be dumped
+        const NODUMP     = 0x1000;
+
+        /// The object is a position-independent executable
+        const PIE        = 0x8000000;
+    }
+}
+
+/// This enum represents a single entry of the dynamic section of an ELF file. Entries, whose value
+/// is an address, contain the virtual address. Entries, whose value is a string, contain the offset
+/// of the string in the dynamic string table (`DT_STRTAB`).
+///
+/// ## See also
+/// - [Dynamic Section](https://www.sco.com/developers/gabi/latest/ch5.dynamic.html) by SCO, Inc.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum DynamicEntry {
+    /// `DT_NULL` - This entry marks the end of the dynamic section
+    Null,
+
+    /// `DT_NEEDED` - This entry holds the string offset of the name of a needed library
+    Needed(u64),
+
+    /// `DT_PLTRELSZ` - This entry holds the total size of the relocations of the PLT
+    PltRelSize(u64),
+
+    /// `DT_PLTGOT` - This entry holds the address of the PLT or GOT
+    PltGot(u64),
+
+    /// `DT_HASH` - This entry holds the address of the SysV symbol hash table
+    Hash(u64),
+
+    /// `DT_STRTAB` - This entry holds the address of the dynamic string table
+    StringTable(u64),
+
+    /// `DT_SYMTAB` - This entry holds the address of the dynamic symbol table
+    SymbolTable(u64),
+
+    /// `DT_RELA` - This entry holds the address of the relocation table with explicit addends
+    Rela(u64),
+
+    /// `DT_RELASZ` - This entry holds the total size of the `DT_RELA` relocation table
+    RelaSize(u64),
+
+    /// `DT_RELAENT` - This entry holds the size of a `DT_RELA` relocation entry
+    RelaEntrySize(u64),
+
+    /// `DT_STRSZ` - This entry holds the size of the dynamic string table
+    StringTableSize(u64),
+
+    /// `DT_SYMENT` - This entry holds the size of a dynamic symbol table entry
+    SymbolEntrySize(u64),
+
+    /// `DT_INIT` - This entry holds the address of the initialization function
+    Init(u64),
+
+    /// `DT_FINI` - This entry holds the address of the termination function
+    Fini(u64),
+
+    /// `DT_SONAME` - This entry holds the string offset of the name of the shared object
+    SoName(u64),
+
+    /// `DT_RPATH` - This entry holds the string offset of the library search path (deprecated)
+    RPath(u64),
+
+    /// `DT_SYMBOLIC` - This entry changes the symbol resolution to start with the object itself
+    Symbolic,
+
+    /// `DT_REL` - This entry holds the address of the relocation table with implicit addends
+    Rel(u64),
+
+    /// `DT_RELSZ` - This entry holds the total size of the `DT_REL` relocation table
+    RelSize(u64),
+
+    /// `DT_RELENT` - This entry holds the size of a `DT_REL` relocation entry
+    RelEntrySize(u64),
+
+    /// `DT_PLTREL` - This entry holds the type of the PLT relocations (`DT_REL` or `DT_RELA`)
+    PltRel(u64),
+
+    /// `DT_DEBUG` - This entry is used for debugging
+    Debug(u64),
+
+    /// `DT_TEXTREL` - This entry signals that relocations may modify a non-writable segment
+    TextRel,
+
+    /// `DT_JMPREL` - This entry holds the address of the relocations of the PLT
+    JmpRel(u64),
+
+    /// `DT_BIND_NOW` - This entry signals that all relocations must be processed on load
+    BindNow,
+
+    /// `DT_INIT_ARRAY` - This entry holds the address of the array of initialization functions
+    InitArray(u64),
+
+    /// `DT_FINI_ARRAY` - This entry holds the address of the array of termination functions
+    FiniArray(u64),
+
+    /// `DT_INIT_ARRAYSZ` - This entry holds the size of the `DT_INIT_ARRAY` array
+    InitArraySize(u64),
+
+    /// `DT_FINI_ARRAYSZ` - This entry holds the size of the `DT_FINI_ARRAY` array
+    FiniArraySize(u64),
+
+    /// `DT_RUNPATH` - This entry holds the string offset of the library search path
+    RunPath(u64),
+
+    /// `DT_FLAGS` - This entry holds the flags of the object. For more information, see
+    /// [DynamicFlags].
+    Flags(DynamicFlags),
+
+    /// `DT_PREINIT_ARRAY` - This entry holds the address of the array of pre-initialization
+    /// functions
+    PreInitArray(u64),
+
+    /// `DT_PREINIT_ARRAYSZ` - This entry holds the size of the `DT_PREINIT_ARRAY` array
+    PreInitArraySize(u64),
+
+    /// `DT_SYMTAB_SHNDX` - This entry holds the address of the extended section indices of the
+    /// dynamic symbol table
+    SymbolTableIndex(u64),
+
+    /// `DT_GNU_HASH` - This entry holds the address of the GNU symbol hash table
+    GnuHash(u64),
+
+    /// `DT_VERSYM` - This entry holds the address of the symbol version table
+    VersionSymbol(u64),
+
+    /// `DT_RELACOUNT` - This entry holds the count of relative `DT_RELA` relocations
+    RelaCount(u64),
+
+    /// `DT_RELCOUNT` - This entry holds the count of relative `DT_REL` relocations
+    RelCount(u64),
+
+    /// `DT_FLAGS_1` - This entry holds the GNU extension flags of the object. For more
+    /// information, see [DynamicFlags1].
+    Flags1(DynamicFlags1),
+
+    /// `DT_VERDEF` - This entry holds the address of the version definition table
+    VersionDefinition(u64),
+
+    /// `DT_VERDEFNUM` - This entry holds the count of entries in the version definition table
+    VersionDefinitionCount(u64),
+
+    /// `DT_VERNEED` - This entry holds the address of the version requirement table
+    VersionRequirement(u64),
+
+    /// `DT_VERNEEDNUM` - This entry holds the count of entries in the version requirement table
+    VersionRequirementCount(u64),
+
+    /// This entry has an unknown tag (first field) with the value (second field)
+    Unknown(u64, u64),
+}
+
+impl DynamicEntry {
+    /// This function returns the size of a single dynamic entry for ELF files with the specified
+    /// class.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass) -> Result<usize, Error> {
+        match class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => Err(Error::InvalidClass),
+            ElfClass::Class32 => Ok(8),
+            ElfClass::Class64 => Ok(16),
+        }
+    }
+
+    /// This function reads the data from the slice (with offset) and parses it into a
+    /// [DynamicEntry] for the ELF file.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the entry could be read completely
+    ///
+    /// ## See also
+    /// - [Dynamic Section](https://www.sco.com/developers/gabi/latest/ch5.dynamic.html) by SCO, Inc.
+    pub fn read(ident: &ElfIdent, slice: &[u8], mut offset: usize) -> Result<Self, Error> {
+        sub_slice(slice, offset, Self::size(ident.class)?, "DynamicEntry")?;
+        let tag = read_class_dependent!(ident, slice, &mut offset);
+        let value = read_class_dependent!(ident, slice, &mut offset);
+        Ok(match tag {
+            0 => Self::Null,
+            1 => Self::Needed(value),
+            2 => Self::PltRelSize(value),
+            3 => Self::PltGot(value),
+            4 => Self::Hash(value),
+            5 => Self::StringTable(value),
+            6 => Self::SymbolTable(value),
+            7 => Self::Rela(value),
+            8 => Self::RelaSize(value),
+            9 => Self::RelaEntrySize(value),
+            10 => Self::StringTableSize(value),
+            11 => Self::SymbolEntrySize(value),
+            12 => Self::Init(value),
+            13 => Self::Fini(value),
+            14 => Self::SoName(value),
+            15 => Self::RPath(value),
+            16 => Self::Symbolic,
+            17 => Self::Rel(value),
+            18 => Self::RelSize(value),
+            19 => Self::RelEntrySize(value),
+            20 => Self::PltRel(value),
+            21 => Self::Debug(value),
+            22 => Self::TextRel,
+            23 => Self::JmpRel(value),
+            24 => Self::BindNow,
+            25 => Self::InitArray(value),
+            26 => Self::FiniArray(value),
+            27 => Self::InitArraySize(value),
+            28 => Self::FiniArraySize(value),
+            29 => Self::RunPath(value),
+            30 => Self::Flags(DynamicFlags::from_bits_retain(value)),
+            32 => Self::PreInitArray(value),
+            33 => Self::PreInitArraySize(value),
+            34 => Self::SymbolTableIndex(value),
+            0x6FFFFEF5 => Self::GnuHash(value),
+            0x6FFFFFF0 => Self::VersionSymbol(value),
+            0x6FFFFFF9 => Self::RelaCount(value),
+            0x6FFFFFFA => Self::RelCount(value),
+            0x6FFFFFFB => Self::Flags1(DynamicFlags1::from_bits_retain(value)),
+            0x6FFFFFFC => Self::VersionDefinition(value),
+            0x6FFFFFFD => Self::VersionDefinitionCount(value),
+            0x6FFFFFFE => Self::VersionRequirement(value),
+            0x6FFFFFFF => Self::VersionRequirementCount(value),
+            tag => Self::Unknown(tag, value),
+        })
+    }
+}
+
+/// This structure represents a view over the dynamic section of an ELF file. The section is an
+/// array of [DynamicEntry] values, which is terminated by a `DT_NULL` entry.
+///
+/// ## See also
+/// - [Dynamic Section](https://www.sco.com/developers/gabi/latest/ch5.dynamic.html) by SCO, Inc.
+#[derive(Clone, Copy, Debug)]
+pub struct DynamicTable<'a> {
+    ident: ElfIdent,
+    bytes: &'a [u8],
+}
+
+impl<'a> DynamicTable<'a> {
+    /// This function creates a dynamic table view over the specified bytes of the dynamic section.
+    #[inline]
+    pub const fn new(ident: ElfIdent, bytes: &'a [u8]) -> Self {
+        Self { ident, bytes }
+    }
+
+    /// This function returns an iterator over all entries of the dynamic table. The iterator ends
+    /// before the terminating `DT_NULL` entry or at the end of the data.
+    pub fn iter(&self) -> impl Iterator<Item = Result<DynamicEntry, Error>> + 'a {
+        let table = *self;
+        let entry_size = DynamicEntry::size(self.ident.class).unwrap_or(usize::MAX);
+        (0..(self.bytes.len() / entry_size))
+            .map(move |index| DynamicEntry::read(&table.ident, table.bytes, index * entry_size))
+            .take_while(|entry| !matches!(entry, Ok(DynamicEntry::Null)))
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns a view over the dynamic section of the ELF file. The section is read
+    /// from the `PT_DYNAMIC` segment or, if there is no such segment, from the `SHT_DYNAMIC`
+    /// section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no dynamic section
+    /// - [Error::NotEnoughBytes] - The data of the dynamic section is not in the ELF data
+    pub fn dynamic_table(&self) -> Result<DynamicTable<'a>, Error> {
+        if let Some(program_header) = self
+            .program_headers
+            .iter()
+            .flatten()
+            .find(|program_header| program_header.ty == SegmentType::Dynamic)
+        {
+            return Ok(DynamicTable::new(
+                self.header.ident,
+                program_header.data(self)?,
+            ));
+        }
+
+        let section_header = self
+            .section_headers
+            .iter()
+            .flatten()
+            .find(|section_header| section_header.ty == SectionType::Dynamic)
+            .ok_or(Error::MissingSection(".dynamic"))?;
+        Ok(DynamicTable::new(
+            self.header.ident,
+            section_header.data(self)?,
+        ))
+    }
+
+    /// This function returns an iterator over all entries of the dynamic section. For more
+    /// information, see [Elf::dynamic_table].
+    #[inline]
+    pub fn dynamic(&self) -> Result<impl Iterator<Item = Result<DynamicEntry, Error>> + 'a, Error> {
+        Ok(self.dynamic_table()?.iter())
+    }
+
+    /// This function returns the value of the first dynamic entry, for which the specified function
+    /// returns a value.
+    pub(crate) fn find_dynamic<T>(
+        &self, f: impl Fn(DynamicEntry) -> Option<T>,
+    ) -> Result<Option<T>, Error> {
+        for entry in self.dynamic()? {
+            if let Some(value) = f(entry?) {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// This function returns a view over the dynamic string table, which is referenced by the
+    /// `DT_STRTAB` and `DT_STRSZ` entries of the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_STRTAB` or `DT_STRSZ` entry
+    /// - [Error::UnmappedAddress] - The address of the string table is not in a loadable segment
+    /// - [Error::UnexpectedEnd] - The string table is not in the ELF data
+    pub fn dynamic_string_table(&self) -> Result<StringTable<'a>, Error> {
+        let address = self
+            .find_dynamic(|entry| {
+                match entry {
+                    DynamicEntry::StringTable(address) => Some(address),
+                    _ => None,
+                }
+            })?
+            .ok_or(Error::MissingDynamicEntry("DT_STRTAB"))?;
+        let size = self
+            .find_dynamic(|entry| {
+                match entry {
+                    DynamicEntry::StringTableSize(size) => Some(size),
+                    _ => None,
+                }
+            })?
+            .ok_or(Error::MissingDynamicEntry("DT_STRSZ"))?;
+
+        Ok(StringTable::new(
+            self.read_at_vaddr(address, size as usize)?,
+        ))
+    }
+
+    /// This function returns a view over the dynamic symbol table, which is referenced by the
+    /// `DT_SYMTAB` entry of the dynamic section. This doesn't need the section headers, so this
+    /// works with stripped ELF files. The count of symbols is read from the `DT_HASH` table or
+    /// derived from the `DT_GNU_HASH` table. If there are no hash tables, the count is derived from
+    /// the dynamic string table following the symbol table.
+    pub(crate) fn dynamic_symbol_table_from_dynamic(&self) -> Result<SymbolTable<'a>, Error> {
+        let address = self
+            .find_dynamic(|entry| {
+                match entry {
+                    DynamicEntry::SymbolTable(address) => Some(address),
+                    _ => None,
+                }
+            })?
+            .ok_or(Error::MissingDynamicEntry("DT_SYMTAB"))?;
+        let entry_size = match self.find_dynamic(|entry| {
+            match entry {
+                DynamicEntry::SymbolEntrySize(size) => Some(size),
+                _ => None,
+            }
+        })? {
+            Some(size) => size,
+            None => Symbol::size(self.header.ident.class)? as u64,
+        };
+
+        // Read count of symbols from the chain count of the SysV hash table, derive the count from
+        // the GNU hash table or use the string table as end of the symbol table
+        let count = match self.hash_table() {
+            Ok(hash_table) => hash_table.chain_count() as u64,
+            Err(Error::MissingDynamicEntry(_)) => {
+                match self.gnu_hash_table() {
+                    Ok(hash_table) => hash_table.symbol_count()? as u64,
+                    Err(Error::MissingDynamicEntry(_)) => {
+                        let string_table_address = self
+                            .find_dynamic(|entry| {
+                                match entry {
+                                    DynamicEntry::StringTable(address) => Some(address),
+                                    _ => None,
+                                }
+                            })?
+                            .ok_or(Error::MissingDynamicEntry("DT_HASH"))?;
+                        string_table_address.saturating_sub(address) / entry_size.max(1)
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            Err(error) => return Err(error),
+        };
+
+        SymbolTable::new(
+            self.header.ident,
+            self.read_at_vaddr(address, count.saturating_mul(entry_size) as usize)?,
+            entry_size as usize,
+            self.dynamic_string_table()?,
+        )
+    }
+
+    /// This function returns the relocation tables, which are referenced by the `DT_RELA`,
+    /// `DT_REL` and `DT_JMPREL` entries of the dynamic section. This doesn't need the section
+    /// headers, so this works with stripped ELF files. The symbols of these relocations are in
+    /// the dynamic symbol table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no dynamic section
+    /// - [Error::MissingDynamicEntry] - The size of a relocation table is not specified
+    /// - [Error::UnmappedAddress] - The address of a table is not in a loadable segment
+    /// - [Error::UnexpectedEnd] - The data of a table is not in the ELF data
+    pub fn dynamic_relocation_tables(&self) -> Result<Vec<RelocationTable<'a>>, Error> {
+        let mut tables = Vec::new();
+        let (mut rela, mut rela_size, mut rela_entry_size) = (None, None, 0);
+        let (mut rel, mut rel_size, mut rel_entry_size) = (None, None, 0);
+        let (mut jmprel, mut jmprel_size, mut jmprel_rela) = (None, None, false);
+        for entry in self.dynamic()? {
+            match entry? {
+                DynamicEntry::Rela(address) => rela = Some(address),
+                DynamicEntry::RelaSize(size) => rela_size = Some(size),
+                DynamicEntry::RelaEntrySize(size) => rela_entry_size = size,
+                DynamicEntry::Rel(address) => rel = Some(address),
+                DynamicEntry::RelSize(size) => rel_size = Some(size),
+                DynamicEntry::RelEntrySize(size) => rel_entry_size = size,
+                DynamicEntry::JmpRel(address) => jmprel = Some(address),
+                DynamicEntry::PltRelSize(size) => jmprel_size = Some(size),
+                DynamicEntry::PltRel(ty) => jmprel_rela = ty == 7,
+                _ => {}
+            }
+        }
+
+        for (address, size, entry_size, with_addend, name) in [
+            (rela, rela_size, rela_entry_size, true, "DT_RELASZ"),
+            (rel, rel_size, rel_entry_size, false, "DT_RELSZ"),
+            (jmprel, jmprel_size, 0, jmprel_rela, "DT_PLTRELSZ"),
+        ] {
+            if let Some(address) = address {
+                let size = size.ok_or(Error::MissingDynamicEntry(name))?;
+                tables.push(RelocationTable::new(
+                    self.header.ident,
+                    self.read_at_vaddr(address, size as usize)?,
+                    entry_size as usize,
+                    with_addend,
+                )?);
+            }
+        }
+        Ok(tables)
+    }
+
+    /// This function resolves the strings of all dynamic entries, for which the specified function
+    /// returns a string offset.
+    fn dynamic_strings(
+        &self, f: impl Fn(DynamicEntry) -> Option<u64>,
+    ) -> Result<Vec<&'a str>, Error> {
+        let mut strings = Vec::new();
+        let mut string_table = None;
+        for entry in self.dynamic()? {
+            if let Some(offset) = f(entry?) {
+                let string_table = match string_table {
+                    Some(string_table) => string_table,
+                    None => *string_table.insert(self.dynamic_string_table()?),
+                };
+                strings.push(string_table.get(offset as usize)?);
+            }
+        }
+        Ok(strings)
+    }
+
+    /// This function returns the names of all libraries, which are needed by this ELF file. The
+    /// names are specified by the `DT_NEEDED` entries of the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_STRTAB` or `DT_STRSZ` entry
+    /// - [Error::UnmappedAddress] - The address of the string table is not in a loadable segment
+    /// - [Error::UnexpectedEnd] - The string table or a name is not in the ELF data
+    /// - [Error::InvalidString] - A name is not valid UTF-8
+    pub fn needed_libraries(&self) -> Result<Vec<&'a str>, Error> {
+        self.dynamic_strings(|entry| {
+            match entry {
+                DynamicEntry::Needed(offset) => Some(offset),
+                _ => None,
+            }
+        })
+    }
+
+    /// This function returns the name of this shared object, which is specified by the `DT_SONAME`
+    /// entry of the dynamic section. If there is no such entry, this function returns None.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_STRTAB` or `DT_STRSZ` entry
+    /// - [Error::UnmappedAddress] - The address of the string table is not in a loadable segment
+    /// - [Error::UnexpectedEnd] - The string table or the name is not in the ELF data
+    /// - [Error::InvalidString] - The name is not valid UTF-8
+    pub fn soname(&self) -> Result<Option<&'a str>, Error> {
+        Ok(self
+            .dynamic_strings(|entry| {
+                match entry {
+                    DynamicEntry::SoName(offset) => Some(offset),
+                    _ => None,
+                }
+            })?
+            .first()
+            .copied())
+    }
+
+    /// This function returns the deprecated library search path of this ELF file, which is
+    /// specified by the `DT_RPATH` entry of the dynamic section. If there is no such entry, this
+    /// function returns None. For a list with all errors, see [Elf::soname].
+    pub fn rpath(&self) -> Result<Option<&'a str>, Error> {
+        Ok(self
+            .dynamic_strings(|entry| {
+                match entry {
+                    DynamicEntry::RPath(offset) => Some(offset),
+                    _ => None,
+                }
+            })?
+            .first()
+            .copied())
+    }
+
+    /// This function returns the library search path of this ELF file, which is specified by the
+    /// `DT_RUNPATH` entry of the dynamic section. If there is no such entry, this function returns
+    /// None. For a list with all errors, see [Elf::soname].
+    pub fn runpath(&self) -> Result<Option<&'a str>, Error> {
+        Ok(self
+            .dynamic_strings(|entry| {
+                match entry {
+                    DynamicEntry::RunPath(offset) => Some(offset),
+                    _ => None,
+                }
+            })?
+            .first()
+            .copied())
+    }
+}
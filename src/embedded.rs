@@ -0,0 +1,80 @@
+use crate::{
+    header::{
+        FileHeader,
+        SectionType,
+    },
+    Elf,
+};
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+impl<'a> Elf<'a> {
+    /// This function returns the size of the ELF file in bytes, which is computed with the end of
+    /// the file header, the header tables and the data of all segments and sections. Data behind
+    /// the ELF file in the specified ELF data (like padding of a firmware image) is not included.
+    pub fn extent(&self) -> u64 {
+        let header = &self.header;
+        let table_end = |offset: u64, count: usize, entry_size: u16| {
+            offset.saturating_add((count as u64).saturating_mul(entry_size as u64))
+        };
+
+        let mut extent = FileHeader::size(header.ident.class).unwrap_or(0) as u64;
+        if let Some(program_headers) = &self.program_headers {
+            extent = extent.max(table_end(
+                header.program_header_offset,
+                program_headers.len(),
+                header.program_header_size,
+            ));
+            for program_header in program_headers {
+                extent = extent.max(
+                    program_header
+                        .offset
+                        .saturating_add(program_header.file_size),
+                );
+            }
+        }
+        if let Some(section_headers) = &self.section_headers {
+            extent = extent.max(table_end(
+                header.section_header_offset,
+                section_headers.len(),
+                header.section_header_size,
+            ));
+            for section_header in section_headers
+                .iter()
+                .filter(|section_header| section_header.ty != SectionType::NoBits)
+            {
+                extent = extent.max(section_header.offset.saturating_add(section_header.size));
+            }
+        }
+        extent
+    }
+
+    /// This function carves all ELF files out of the specified data (like a firmware image, memory
+    /// dump or disk image). Every occurrence of the magic bytes is parsed as candidate and the
+    /// candidates with valid headers, which are completely in the data, are returned as tuple of
+    /// offset in the data, length and the ELF file over the carved data. ELF files inside of other
+    /// ELF files are returned too.
+    ///
+    /// ## See also
+    /// - [Elf::find_embedded] for the first ELF file in the data
+    pub fn carve_embedded(bytes: &'a [u8]) -> Vec<(usize, usize, Self)> {
+        let mut images = Vec::new();
+        let mut offset = 0;
+        while let Some(index) = Self::elf_index(&bytes[offset..]) {
+            offset += index;
+            let data = &bytes[offset..];
+            if let Some(length) = Self::from_bytes(data)
+                .ok()
+                .and_then(|elf| usize::try_from(elf.extent()).ok())
+                .filter(|length| *length <= data.len())
+            {
+                // Parse the candidate again, so the view is limited to the carved data
+                if let Ok(elf) = Self::from_bytes(&data[..length]) {
+                    images.push((offset, length, elf));
+                }
+            }
+            offset += 1;
+        }
+        images
+    }
+}
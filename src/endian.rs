@@ -1,42 +1,75 @@
-use crate::header::ident::ElfEndian;
+use crate::{
+    header::ident::ElfEndian,
+    Error,
+};
 
 impl ElfEndian {
+    /// This function reads a value of the specified type with this endianness from the slice. If an
+    /// offset is specified, the value is read at the offset and the offset is advanced by the size
+    /// of the value.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The slice ends before the value could be read completely
+    /// - [Error::InvalidEndian] - The endianness of the provided ELF file is not valid
     #[inline]
-    pub fn read<T: EndianReader>(&self, slice: &[u8], offset: Option<&mut usize>) -> Option<T> {
+    pub fn read<T: EndianReader>(
+        &self, slice: &[u8], offset: Option<&mut usize>,
+    ) -> Result<T, Error> {
         T::read_with_endian(slice, *self, offset)
     }
 }
 
 pub trait EndianReader {
-    fn read_with_endian(slice: &[u8], endian: ElfEndian, offset: Option<&mut usize>) -> Option<Self>
+    fn read_with_endian(
+        slice: &[u8], endian: ElfEndian, offset: Option<&mut usize>,
+    ) -> Result<Self, Error>
     where
         Self: Sized;
 }
 
+/// This function returns the sub-slice with the specified size at the specified offset of the slice.
+/// If the slice is too short for that, this function returns an [Error::UnexpectedEnd] with the
+/// specified name of the structure and the offset.
+#[inline]
+pub(crate) fn sub_slice<'a>(
+    slice: &'a [u8], offset: usize, size: usize, structure: &'static str,
+) -> Result<&'a [u8], Error> {
+    offset
+        .checked_add(size)
+        .and_then(|end| slice.get(offset..end))
+        .ok_or(Error::UnexpectedEnd(structure, offset))
+}
+
 macro_rules! impl_endian_reader {
     ($ty: ty) => {
         impl EndianReader for $ty {
             fn read_with_endian(
                 slice: &[u8], endian: ElfEndian, offset: Option<&mut usize>,
-            ) -> Option<Self> {
+            ) -> Result<Self, Error> {
                 const SELF_SIZE: usize = crate::std::mem::size_of::<$ty>();
 
                 let offset_usize = offset.as_ref().map(|value| **value).unwrap_or(0);
-                let slice = slice.get(offset_usize..(offset_usize + SELF_SIZE)).unwrap();
+                let mut bytes = [0_u8; SELF_SIZE];
+                bytes.copy_from_slice(sub_slice(slice, offset_usize, SELF_SIZE, stringify!($ty))?);
+
+                let value = match endian {
+                    ElfEndian::Big => Self::from_be_bytes(bytes),
+                    ElfEndian::Little => Self::from_le_bytes(bytes),
+                    ElfEndian::Invalid | ElfEndian::Unknown(_) => return Err(Error::InvalidEndian),
+                };
+
                 if let Some(offset) = offset {
                     *offset += SELF_SIZE;
                 }
-
-                match endian {
-                    ElfEndian::Big => Some(Self::from_be_bytes(slice.try_into().unwrap())),
-                    ElfEndian::Little => Some(Self::from_le_bytes(slice.try_into().unwrap())),
-                    _ => None,
-                }
+                Ok(value)
             }
         }
     };
 }
 
+impl_endian_reader!(u8);
 impl_endian_reader!(u16);
 impl_endian_reader!(u32);
 impl_endian_reader!(u64);
+impl_endian_reader!(i32);
+impl_endian_reader!(i64);
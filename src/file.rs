@@ -0,0 +1,110 @@
+use crate::{
+    Elf,
+    Error,
+};
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    io::Read,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// This enum represents the storage of the data of an [ElfFile]. The data is memory-mapped if
+/// possible, otherwise the data is read into the memory.
+#[derive(Debug)]
+enum ElfFileData {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+/// This structure represents an ELF file, which is opened from a path and owns its data. The data
+/// is memory-mapped, so large files (like debug files) are not copied into the memory. If the file
+/// can't be mapped, the file is read completely. The parsed ELF file is created with
+/// [ElfFile::elf]. (Only available with `std`-feature)
+///
+/// The memory-mapped data can change, if the file is modified by another process while it's
+/// opened. This is the same as with other tools, that memory-map ELF files (like linkers).
+#[derive(Debug)]
+pub struct ElfFile {
+    path: PathBuf,
+    data: ElfFileData,
+}
+
+impl ElfFile {
+    /// This function opens the ELF file at the specified path and memory-maps its data. If the file
+    /// can't be mapped (e.g. empty files or special files), the data of the file is read into the
+    /// memory. The ELF file is validated while opening.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::IO] - The file can't be opened or read
+    /// - For errors while parsing the ELF file, see [Elf::from_bytes]
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let mut file = File::open(path.as_ref())?;
+
+        // SAFETY: The mapping is only read and the file is opened by this structure. Modifications
+        // by other processes are documented in the structure.
+        let data = match unsafe { Mmap::map(&file) } {
+            Ok(mmap) => ElfFileData::Mapped(mmap),
+            Err(_) => {
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)?;
+                ElfFileData::Owned(bytes)
+            }
+        };
+        Self::with_data(path.as_ref(), data)
+    }
+
+    /// This function opens the ELF file at the specified path and reads its data into the memory
+    /// without memory-mapping. The ELF file is validated while opening.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::IO] - The file can't be opened or read
+    /// - For errors while parsing the ELF file, see [Elf::from_bytes]
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let bytes = std::fs::read(path.as_ref())?;
+        Self::with_data(path.as_ref(), ElfFileData::Owned(bytes))
+    }
+
+    /// This function validates the specified data and creates the ELF file.
+    fn with_data(path: &Path, data: ElfFileData) -> Result<Self, Error> {
+        let file = Self {
+            path: path.to_path_buf(),
+            data,
+        };
+        file.elf()?;
+        Ok(file)
+    }
+
+    /// This function returns the path, which was used to open the ELF file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// This function returns whether the data of the ELF file is memory-mapped.
+    #[inline]
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.data, ElfFileData::Mapped(_))
+    }
+
+    /// This function returns the data of the ELF file.
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        match &self.data {
+            ElfFileData::Mapped(mmap) => mmap,
+            ElfFileData::Owned(bytes) => bytes,
+        }
+    }
+
+    /// This function parses the data of the ELF file and returns a view over the data. The view
+    /// borrows the data of this structure, so no data is copied.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while parsing the ELF file, see [Elf::from_bytes]
+    pub fn elf(&self) -> Result<Elf<'_>, Error> {
+        Elf::from_bytes(self.bytes())
+    }
+}
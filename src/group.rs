@@ -0,0 +1,113 @@
+use crate::{
+    header::{
+        SectionHeader,
+        SectionType,
+    },
+    symbol::{
+        Symbol,
+        SymbolTable,
+    },
+    Elf,
+    Error,
+};
+use bitflags::bitflags;
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+bitflags! {
+    /// This structure contains the flags of a section group, which are stored in the first word of
+    /// the group section.
+    ///
+    /// ## See also
+    /// - [Section Groups](https://www.sco.com/developers/gabi/latest/ch4.sheader.html#section_groups)
+    ///   by SCO, Inc.
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct GroupFlags: u32 {
+        /// The group is a COMDAT group, so the linker keeps only one group with the same signature
+        const COMDAT = 0x1;
+    }
+}
+
+/// This structure represents a section group (`SHT_GROUP`) of a relocatable ELF file. The sections
+/// of a group must be kept or discarded together by the linker. The group is identified by the
+/// name of its signature symbol.
+///
+/// ## See also
+/// - [Section Groups](https://www.sco.com/developers/gabi/latest/ch4.sheader.html#section_groups) by
+///   SCO, Inc.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct SectionGroup<'a> {
+    /// This field indicates the index of the group section in the section header table.
+    pub section_index: usize,
+
+    /// This field indicates the flags of the group. For more information, see [GroupFlags].
+    pub flags: GroupFlags,
+
+    /// This field indicates the name of the signature symbol, which identifies the group.
+    pub signature: &'a str,
+
+    /// This field contains the signature symbol, which is referenced by the `info` field of the
+    /// group section in the symbol table linked by the `link` field.
+    pub signature_symbol: Symbol,
+
+    /// This field contains the indices of the member sections in the section header table.
+    pub members: Vec<u32>,
+}
+
+impl<'a> SectionGroup<'a> {
+    /// This function reads the group of the specified group section, which has the specified
+    /// index in the section header table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the group is not in the ELF data
+    /// - [Error::UnexpectedEnd] - The group has no flags or the signature symbol is not in the symbol
+    ///   table
+    /// - [Error::InvalidSectionIndex] - The linked symbol table is not in the section header table
+    /// - [Error::InvalidString] - The name of the signature symbol is not valid UTF-8
+    pub fn read(
+        elf: &Elf<'a>, section_index: usize, section_header: &SectionHeader,
+    ) -> Result<Self, Error> {
+        // Read the signature symbol with the linked symbol table
+        let symbol_table =
+            SymbolTable::from_section(elf, elf.section_header(section_header.link as usize)?)?;
+        let signature_symbol = symbol_table.get(section_header.info as usize)?;
+
+        // Read the flags and the member indices after the flags
+        let endian = &elf.header.ident.endian;
+        let data = section_header.data(elf)?;
+        let flags = GroupFlags::from_bits_retain(endian.read(data, Some(&mut 0))?);
+        let members = (1..(data.len() / 4))
+            .map(|index| endian.read::<u32>(data, Some(&mut (index * 4))))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            section_index,
+            flags,
+            signature: symbol_table.name(&signature_symbol)?,
+            signature_symbol,
+            members,
+        })
+    }
+
+    /// This function returns whether the group is a COMDAT group.
+    #[inline]
+    pub const fn is_comdat(&self) -> bool {
+        self.flags.contains(GroupFlags::COMDAT)
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns all section groups of the ELF file. For more information, see
+    /// [SectionGroup].
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading a group, see [SectionGroup::read]
+    pub fn section_groups(&self) -> Result<Vec<SectionGroup<'a>>, Error> {
+        self.section_headers
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, section_header)| section_header.ty == SectionType::Group)
+            .map(|(index, section_header)| SectionGroup::read(self, index, section_header))
+            .collect()
+    }
+}
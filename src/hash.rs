@@ -0,0 +1,451 @@
+use crate::{
+    dynamic::DynamicEntry,
+    endian::sub_slice,
+    header::{
+        ident::{
+            ElfClass,
+            ElfIdent,
+        },
+        read_class_dependent,
+        SectionHeader,
+        SectionType,
+    },
+    symbol::{
+        Symbol,
+        SymbolTable,
+    },
+    Elf,
+    Error,
+};
+
+/// This function calculates the hash of the specified symbol name with the standard ELF hash
+/// function, which is used by the `SHT_HASH` section.
+///
+/// ## See also
+/// - [Hash Table](https://www.sco.com/developers/gabi/latest/ch5.dynamic.html#hash) by SCO, Inc.
+pub fn elf_hash(name: &[u8]) -> u32 {
+    let mut hash = 0_u32;
+    for byte in name {
+        hash = (hash << 4).wrapping_add(*byte as u32);
+        let high = hash & 0xF0000000;
+        hash ^= high >> 24;
+        hash &= !high;
+    }
+    hash
+}
+
+/// This function calculates the hash of the specified symbol name with the GNU hash function (DJB
+/// hash), which is used by the `SHT_GNU_HASH` section.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381_u32, |hash, byte| {
+        hash.wrapping_mul(33).wrapping_add(*byte as u32)
+    })
+}
+
+/// This structure represents a view over the SysV hash table (`SHT_HASH` or `DT_HASH`) of an ELF
+/// file. The table contains the buckets and chains of 32-bit words, which map the hashes of the
+/// symbol names to the indices of the symbols in the dynamic symbol table.
+///
+/// ## See also
+/// - [Hash Table](https://www.sco.com/developers/gabi/latest/ch5.dynamic.html#hash) by SCO, Inc.
+#[derive(Clone, Copy, Debug)]
+pub struct SysvHashTable<'a> {
+    ident: ElfIdent,
+    buckets: &'a [u8],
+    chains: &'a [u8],
+}
+
+impl<'a> SysvHashTable<'a> {
+    /// This function creates a hash table view over the specified bytes of the table. The bytes
+    /// must contain the bucket count, the chain count, the buckets and the chains.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The buckets or chains end outside of the bytes
+    pub fn new(ident: ElfIdent, bytes: &'a [u8]) -> Result<Self, Error> {
+        let (bucket_count, chain_count) = Self::counts(&ident, bytes)?;
+        let buckets = sub_slice(bytes, 8, bucket_count.saturating_mul(4), "SysvHashTable")?;
+        let chains = sub_slice(
+            bytes,
+            8 + buckets.len(),
+            chain_count.saturating_mul(4),
+            "SysvHashTable",
+        )?;
+        Ok(Self {
+            ident,
+            buckets,
+            chains,
+        })
+    }
+
+    /// This function creates a hash table view over the data of the specified section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    /// - For more errors, see [SysvHashTable::new]
+    pub fn from_section(elf: &Elf<'a>, section_header: &SectionHeader) -> Result<Self, Error> {
+        Self::new(elf.header.ident, section_header.data(elf)?)
+    }
+
+    /// This function reads the bucket count and chain count of the table in the specified bytes.
+    fn counts(ident: &ElfIdent, bytes: &[u8]) -> Result<(usize, usize), Error> {
+        let mut offset = 0;
+        let bucket_count = ident.endian.read::<u32>(bytes, Some(&mut offset))? as usize;
+        let chain_count = ident.endian.read::<u32>(bytes, Some(&mut offset))? as usize;
+        Ok((bucket_count, chain_count))
+    }
+
+    /// This function returns the count of buckets in the hash table.
+    #[inline]
+    pub const fn bucket_count(&self) -> usize {
+        self.buckets.len() / 4
+    }
+
+    /// This function returns the count of chains in the hash table. This is equal to the count of
+    /// symbols in the dynamic symbol table.
+    #[inline]
+    pub const fn chain_count(&self) -> usize {
+        self.chains.len() / 4
+    }
+
+    /// This function returns the index of the first symbol in the specified bucket.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the buckets
+    pub fn bucket(&self, index: usize) -> Result<u32, Error> {
+        self.ident
+            .endian
+            .read(self.buckets, Some(&mut index.saturating_mul(4)))
+    }
+
+    /// This function returns the index of the next symbol in the chain of the specified symbol.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the chains
+    pub fn chain(&self, index: usize) -> Result<u32, Error> {
+        self.ident
+            .endian
+            .read(self.chains, Some(&mut index.saturating_mul(4)))
+    }
+
+    /// This function searches the symbol with the specified name in the specified symbol table,
+    /// which must be the symbol table associated with this hash table. If there is no such symbol,
+    /// this function returns None. Otherwise the index and the symbol are returned.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - A bucket, chain, symbol or name ends outside of the tables
+    /// - [Error::InvalidString] - The name of a symbol is not valid UTF-8
+    pub fn lookup(
+        &self, symbols: &SymbolTable<'a>, name: &str,
+    ) -> Result<Option<(usize, Symbol)>, Error> {
+        if self.buckets.is_empty() {
+            return Ok(None);
+        }
+
+        // Follow the chain of the bucket, but stop on cycles in malformed tables
+        let bucket = elf_hash(name.as_bytes()) as usize % self.bucket_count();
+        let mut index = self.bucket(bucket)? as usize;
+        for _ in 0..self.chain_count() {
+            if index == 0 {
+                break;
+            }
+
+            let symbol = symbols.get(index)?;
+            if symbols.name(&symbol)? == name {
+                return Ok(Some((index, symbol)));
+            }
+            index = self.chain(index)? as usize;
+        }
+        Ok(None)
+    }
+}
+
+/// This structure represents a view over the GNU hash table (`SHT_GNU_HASH` or `DT_GNU_HASH`) of
+/// an ELF file. The table contains a bloom filter to reject missing symbols quickly, the buckets
+/// and the chains with the hashes of the symbols. The hashed symbols are sorted by their bucket
+/// and start at the symbol offset of the table in the dynamic symbol table.
+///
+/// ## See also
+/// - [GNU Hash ELF Sections](https://blogs.oracle.com/solaris/post/gnu-hash-elf-sections) by Ali
+///   Bahrami
+#[derive(Clone, Copy, Debug)]
+pub struct GnuHashTable<'a> {
+    ident: ElfIdent,
+    symbol_offset: u32,
+    bloom_shift: u32,
+    bloom: &'a [u8],
+    buckets: &'a [u8],
+    chains: &'a [u8],
+}
+
+impl<'a> GnuHashTable<'a> {
+    /// This function creates a hash table view over the specified bytes of the table. The bloom
+    /// words are 32-bit in 32-bit ELF files and 64-bit in 64-bit ELF files. The chains span the
+    /// remaining bytes after the buckets, because their count is not stored in the table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The header, bloom filter or buckets end outside of the bytes
+    /// - [Error::InvalidBloomShift] - The bloom filter shift is not lower than the hash width
+    pub fn new(ident: ElfIdent, bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut offset = 0;
+        let bucket_count = ident.endian.read::<u32>(bytes, Some(&mut offset))? as usize;
+        let symbol_offset = ident.endian.read::<u32>(bytes, Some(&mut offset))?;
+        let bloom_size = ident.endian.read::<u32>(bytes, Some(&mut offset))? as usize;
+        let bloom_shift = ident.endian.read::<u32>(bytes, Some(&mut offset))?;
+        if bloom_shift >= u32::BITS {
+            return Err(Error::InvalidBloomShift(bloom_shift));
+        }
+
+        let word_size = match ident.class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => return Err(Error::InvalidClass),
+            ElfClass::Class32 => 4,
+            ElfClass::Class64 => 8,
+        };
+        let bloom = sub_slice(
+            bytes,
+            offset,
+            bloom_size.saturating_mul(word_size),
+            "GnuHashTable",
+        )?;
+        offset += bloom.len();
+        let buckets = sub_slice(
+            bytes,
+            offset,
+            bucket_count.saturating_mul(4),
+            "GnuHashTable",
+        )?;
+        offset += buckets.len();
+        Ok(Self {
+            ident,
+            symbol_offset,
+            bloom_shift,
+            bloom,
+            buckets,
+            chains: &bytes[offset..],
+        })
+    }
+
+    /// This function creates a hash table view over the data of the specified section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    /// - For more errors, see [GnuHashTable::new]
+    pub fn from_section(elf: &Elf<'a>, section_header: &SectionHeader) -> Result<Self, Error> {
+        Self::new(elf.header.ident, section_header.data(elf)?)
+    }
+
+    /// This function returns the count of buckets in the hash table.
+    #[inline]
+    pub const fn bucket_count(&self) -> usize {
+        self.buckets.len() / 4
+    }
+
+    /// This function returns the index of the first symbol in the dynamic symbol table, which is
+    /// accessible with the hash table.
+    #[inline]
+    pub const fn symbol_offset(&self) -> u32 {
+        self.symbol_offset
+    }
+
+    /// This function returns the count of words in the bloom filter.
+    #[inline]
+    pub const fn bloom_size(&self) -> usize {
+        match self.ident.class {
+            ElfClass::Class32 => self.bloom.len() / 4,
+            _ => self.bloom.len() / 8,
+        }
+    }
+
+    /// This function returns the index of the first symbol in the specified bucket. If the bucket
+    /// is empty, the index is zero.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the buckets
+    pub fn bucket(&self, index: usize) -> Result<u32, Error> {
+        self.ident
+            .endian
+            .read(self.buckets, Some(&mut index.saturating_mul(4)))
+    }
+
+    /// This function returns the chain value of the symbol with the specified index in the dynamic
+    /// symbol table. The value is the hash of the symbol name, which has the lowest bit set if the
+    /// symbol is the last symbol of the bucket.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The symbol is not in the chains
+    pub fn chain(&self, index: usize) -> Result<u32, Error> {
+        let chain_index = index
+            .checked_sub(self.symbol_offset as usize)
+            .ok_or(Error::UnexpectedEnd("GnuHashTable", 0))?;
+        self.ident
+            .endian
+            .read(self.chains, Some(&mut chain_index.saturating_mul(4)))
+    }
+
+    /// This function tests the specified hash with the bloom filter. If this function returns
+    /// false, there is no symbol with this hash in the table. Otherwise the symbol may be in the
+    /// table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub fn may_contain(&self, hash: u32) -> Result<bool, Error> {
+        let bloom_size = self.bloom_size();
+        if bloom_size == 0 {
+            return Ok(true);
+        }
+
+        let bits = self.bloom.len() as u32 / bloom_size as u32 * 8;
+        let index = (hash / bits) as usize % bloom_size;
+        let bloom = self.bloom;
+        let word = read_class_dependent!(self.ident, bloom, &mut (index * (bits as usize / 8)));
+        let mask = (1 << (hash % bits)) | (1 << ((hash >> self.bloom_shift) % bits));
+        Ok(word & mask == mask)
+    }
+
+    /// This function searches the symbol with the specified name in the specified symbol table,
+    /// which must be the symbol table associated with this hash table. If there is no such symbol,
+    /// this function returns None. Otherwise the index and the symbol are returned.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - A bloom word, bucket, chain, symbol or name ends outside of the
+    ///   tables
+    /// - [Error::InvalidString] - The name of a symbol is not valid UTF-8
+    pub fn lookup(
+        &self, symbols: &SymbolTable<'a>, name: &str,
+    ) -> Result<Option<(usize, Symbol)>, Error> {
+        let hash = gnu_hash(name.as_bytes());
+        if self.buckets.is_empty() || !self.may_contain(hash)? {
+            return Ok(None);
+        }
+
+        // Walk the chain of the bucket until the last symbol of the bucket
+        let mut index = self.bucket(hash as usize % self.bucket_count())? as usize;
+        if index < self.symbol_offset as usize {
+            return Ok(None);
+        }
+        loop {
+            let chain = self.chain(index)?;
+            if chain | 1 == hash | 1 {
+                let symbol = symbols.get(index)?;
+                if symbols.name(&symbol)? == name {
+                    return Ok(Some((index, symbol)));
+                }
+            }
+            if chain & 1 != 0 {
+                return Ok(None);
+            }
+            index += 1;
+        }
+    }
+
+    /// This function derives the count of symbols in the dynamic symbol table. The last symbol is
+    /// the last symbol in the chain of the bucket with the highest start index.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - A bucket or chain ends outside of the table
+    pub fn symbol_count(&self) -> Result<usize, Error> {
+        let mut index = 0;
+        for bucket in 0..self.bucket_count() {
+            index = index.max(self.bucket(bucket)? as usize);
+        }
+        if index < self.symbol_offset as usize {
+            return Ok(self.symbol_offset as usize);
+        }
+
+        while self.chain(index)? & 1 == 0 {
+            index += 1;
+        }
+        Ok(index + 1)
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns a view over the SysV hash table of the ELF file. The table is read
+    /// from the `SHT_HASH` section or, if there is no such section, with the `DT_HASH` entry of
+    /// the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no hash section and dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_HASH` entry
+    /// - [Error::UnmappedAddress] - The address of the table is not in a loadable segment
+    /// - For more errors, see [SysvHashTable::from_section]
+    pub fn hash_table(&self) -> Result<SysvHashTable<'a>, Error> {
+        if let Some(section_header) = self
+            .section_headers
+            .iter()
+            .flatten()
+            .find(|section_header| section_header.ty == SectionType::Hash)
+        {
+            return SysvHashTable::from_section(self, section_header);
+        }
+
+        let address = self
+            .find_dynamic(|entry| {
+                match entry {
+                    DynamicEntry::Hash(address) => Some(address),
+                    _ => None,
+                }
+            })?
+            .ok_or(Error::MissingDynamicEntry("DT_HASH"))?;
+        let (bucket_count, chain_count) =
+            SysvHashTable::counts(&self.header.ident, self.read_at_vaddr(address, 8)?)?;
+        let size = bucket_count
+            .saturating_add(chain_count)
+            .saturating_add(2)
+            .saturating_mul(4);
+        SysvHashTable::new(self.header.ident, self.read_at_vaddr(address, size)?)
+    }
+
+    /// This function returns a view over the GNU hash table of the ELF file. The table is read
+    /// from the `SHT_GNU_HASH` section or, if there is no such section, with the `DT_GNU_HASH`
+    /// entry of the dynamic section. In this case, the chains span the rest of the loadable
+    /// segment.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no GNU hash section and dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_GNU_HASH` entry
+    /// - [Error::UnmappedAddress] - The address of the table is not in a loadable segment
+    /// - For more errors, see [GnuHashTable::from_section]
+    pub fn gnu_hash_table(&self) -> Result<GnuHashTable<'a>, Error> {
+        if let Some(section_header) = self
+            .section_headers
+            .iter()
+            .flatten()
+            .find(|section_header| section_header.ty == SectionType::GnuHash)
+        {
+            return GnuHashTable::from_section(self, section_header);
+        }
+
+        let address = self
+            .find_dynamic(|entry| {
+                match entry {
+                    DynamicEntry::GnuHash(address) => Some(address),
+                    _ => None,
+                }
+            })?
+            .ok_or(Error::MissingDynamicEntry("DT_GNU_HASH"))?;
+        GnuHashTable::new(self.header.ident, self.read_to_segment_end(address)?)
+    }
+
+    /// This function searches the dynamic symbol with the specified name with the GNU hash table
+    /// or, if there is no such table, with the SysV hash table. If there is no such symbol, this
+    /// function returns None. Otherwise the index and the symbol are returned.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading the tables, see [Elf::gnu_hash_table], [Elf::hash_table] and
+    ///   [Elf::dynamic_symbol_table]
+    /// - For errors while searching the symbol, see [GnuHashTable::lookup] and
+    ///   [SysvHashTable::lookup]
+    pub fn lookup_dynamic_symbol(&self, name: &str) -> Result<Option<(usize, Symbol)>, Error> {
+        match self.gnu_hash_table() {
+            Ok(hash_table) => hash_table.lookup(&self.dynamic_symbol_table()?, name),
+            Err(Error::MissingDynamicEntry(_)) => {
+                self.hash_table()?
+                    .lookup(&self.dynamic_symbol_table()?, name)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    endian::sub_slice,
+    Error,
+};
+
 /// This enum represents the `ELF_CLASS` field of the ident bytes in the header. This can be none if
 /// the class is invalid, `CLASS32` if this file is a 32-bit object or `CLASS64` if this file is a
 /// 64-bit object.
@@ -5,13 +10,27 @@
 /// - [ElfClass::Invalid]: Invalid class specified
 /// - [ElfClass::Class32]: 32-bit ELF File
 /// - [ElfClass::Class64]: 64-bit ELF file
+/// - [ElfClass::Unknown]: Unknown class value
 #[repr(u8)]
+#[rustfmt::skip]
 #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
 pub enum ElfClass {
     #[default]
-    Invalid = 0,
-    Class32 = 1,
-    Class64 = 2,
+    Invalid     = 0,
+    Class32     = 1,
+    Class64     = 2,
+    Unknown(u8) = 3
+}
+
+impl From<u8> for ElfClass {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Invalid,
+            1 => Self::Class32,
+            2 => Self::Class64,
+            value => Self::Unknown(value),
+        }
+    }
 }
 
 /// This enum represents the `ELF_DATA` field of the ident bytes in the header. This can be none if
@@ -21,13 +40,27 @@ pub enum ElfClass {
 /// - [ElfEndian::Invalid]: Invalid endian specified
 /// - [ElfEndian::Little]: Little endian
 /// - [ElfEndian::Big]: Big endian
+/// - [ElfEndian::Unknown]: Unknown endian value
 #[repr(u8)]
+#[rustfmt::skip]
 #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
 pub enum ElfEndian {
     #[default]
-    Invalid = 0,
-    Little = 1,
-    Big = 2,
+    Invalid     = 0,
+    Little      = 1,
+    Big         = 2,
+    Unknown(u8) = 3
+}
+
+impl From<u8> for ElfEndian {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Invalid,
+            1 => Self::Little,
+            2 => Self::Big,
+            value => Self::Unknown(value),
+        }
+    }
 }
 
 /// This enum represents the version of the ELF file. This can currently be the current version (1)
@@ -35,12 +68,25 @@ pub enum ElfEndian {
 ///
 /// - [ElfVersion::Invalid]: Invalid ELF version specified
 /// - [ElfVersion::Current]: Current ELF version
+/// - [ElfVersion::Unknown]: Unknown ELF version
 #[repr(u8)]
+#[rustfmt::skip]
 #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
 pub enum ElfVersion {
-    Invalid = 0,
+    Invalid     = 0,
     #[default]
-    Current = 1,
+    Current     = 1,
+    Unknown(u8) = 2
+}
+
+impl From<u8> for ElfVersion {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Invalid,
+            1 => Self::Current,
+            value => Self::Unknown(value),
+        }
+    }
 }
 
 #[repr(u8)]
@@ -98,12 +144,39 @@ pub enum ElfOsABI {
 
     /// Stratus Technologies OpenVOS
     OpenVOX = 0x12,
+
+    /// Unknown or unsupported OS ABI
+    Unknown(u8) = 0x13,
+}
+
+impl From<u8> for ElfOsABI {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => Self::Unspecified,
+            0x01 => Self::HP_UX,
+            0x02 => Self::NetBSD,
+            0x03 => Self::GNU,
+            0x06 => Self::Solaris,
+            0x07 => Self::AIX,
+            0x08 => Self::Irix,
+            0x09 => Self::FreeBSD,
+            0x0A => Self::Tru64,
+            0x0B => Self::Modesto,
+            0x0C => Self::OpenBSD,
+            0x0D => Self::OpenVMS,
+            0x0E => Self::NSK,
+            0x0F => Self::AROS,
+            0x10 => Self::FenixOS,
+            0x11 => Self::CloudABI,
+            0x12 => Self::OpenVOX,
+            value => Self::Unknown(value),
+        }
+    }
 }
 
-/// This structure represents the 9 bytes of the ident bytes, which can be found in the
-/// [super::ElfHeader]. These bytes indicate the class, endianness, version, OS ABI and some more
+/// This structure represents the ident bytes after the magic bytes, which can be found in the
+/// [super::FileHeader]. These bytes indicate the class, endianness, version, OS ABI and some more
 /// data of the ELF file.
-#[repr(C)]
 #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
 pub struct ElfIdent {
     /// This byte indicates the class of the ELF file. More details can be found in the [ElfClass]
@@ -125,3 +198,36 @@ pub struct ElfIdent {
     /// This byte indicates the ABI extensions version of the ELF file.
     pub abi_version: u8,
 }
+
+impl ElfIdent {
+    /// This field contains the size of the ident bytes after the magic bytes (including the
+    /// padding bytes `EI_PAD`)
+    pub const SIZE: usize = 12;
+
+    /// This function parses the ident bytes at the specified offset (behind the magic bytes) byte
+    /// by byte. Unknown values are parsed into the `Unknown` variants of the enums, but the
+    /// version must be the current version and the padding bytes must be zero.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The slice ends before the ident bytes could be read completely
+    /// - [Error::InvalidVersion] - The version of the ident bytes is not the current version
+    /// - [Error::InvalidPadding] - The padding bytes of the ident bytes are not zero
+    pub fn read(slice: &[u8], offset: usize) -> Result<Self, Error> {
+        let bytes = sub_slice(slice, offset, Self::SIZE, "ElfIdent")?;
+        let ident = Self {
+            class: ElfClass::from(bytes[0]),
+            endian: ElfEndian::from(bytes[1]),
+            version: ElfVersion::from(bytes[2]),
+            abi: ElfOsABI::from(bytes[3]),
+            abi_version: bytes[4],
+        };
+
+        if ident.version != ElfVersion::Current {
+            return Err(Error::InvalidVersion);
+        }
+        if bytes[5..].iter().any(|byte| *byte != 0) {
+            return Err(Error::InvalidPadding);
+        }
+        Ok(ident)
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
+    endian::sub_slice,
     header::ident::{
         ElfClass,
         ElfIdent,
     },
-    std::mem,
     Elf,
     Error,
 };
@@ -14,23 +14,24 @@ pub mod ident;
 macro_rules! read_class_dependent {
     ($ident_field: expr, $slice_field: ident, $offset: expr) => {
         match $ident_field.class {
-            ElfClass::Invalid => return Err(Error::InvalidClass),
-            ElfClass::Class32 => {
+            $crate::header::ident::ElfClass::Invalid
+            | $crate::header::ident::ElfClass::Unknown(_) => return Err($crate::Error::InvalidClass),
+            $crate::header::ident::ElfClass::Class32 => {
                 $ident_field
                     .endian
-                    .read::<u32>($slice_field, Some($offset))
-                    .unwrap() as u64
+                    .read::<u32>($slice_field, Some($offset))? as u64
             }
-            ElfClass::Class64 => {
+            $crate::header::ident::ElfClass::Class64 => {
                 $ident_field
                     .endian
-                    .read::<u64>($slice_field, Some($offset))
-                    .unwrap()
+                    .read::<u64>($slice_field, Some($offset))?
             }
         }
     };
 }
 
+pub(crate) use read_class_dependent;
+
 /// This enum represents the type of the ELF file. The file can be a relocatable file, an executable
 /// file, an shared object or an core file.
 ///
@@ -72,6 +73,7 @@ impl From<u16> for FileType {
 /// unknown or invalid target or one of the valid entries like x86_64 or ARM.
 ///
 /// - [TargetMachine::None]: Unknown or invalid target architecture
+/// - [TargetMachine::X86]: x86/i386 as target architecture
 /// - [TargetMachine::X86_64]: x86_64 as target architecture
 /// - [TargetMachine::ARM]: ARM/AArch32 as target architecture
 /// - [TargetMachine::ARM64]: ARM64/AArch64 as target architecture
@@ -85,6 +87,7 @@ impl From<u16> for FileType {
 pub enum TargetMachine {
     #[default]
     None   = 0,
+    X86    = 3,
     X86_64 = 62,
     ARM    = 40,
     ARM64  = 183,
@@ -94,6 +97,7 @@ pub enum TargetMachine {
 impl From<u16> for TargetMachine {
     fn from(value: u16) -> Self {
         match value {
+            3 => Self::X86,
             62 => Self::X86_64,
             40 => Self::ARM,
             183 => Self::ARM64,
@@ -151,7 +155,9 @@ pub struct FileHeader {
     pub program_header_size: u16,
 
     /// This field indicates the count of the program headers in the file. If there are no program
-    /// header, this value is zero.
+    /// header, this value is zero. If the count doesn't fit into this field, this value is
+    /// [FileHeader::EXTENDED_COUNT] and the count is in the `info` field of the first section
+    /// header.
     pub program_header_count: u16,
 
     /// This field indicates the size of a single section header. All section headers have the same
@@ -159,40 +165,59 @@ pub struct FileHeader {
     pub section_header_size: u16,
 
     /// This field indicates the count of the section headers in the file. If there are no section
-    /// header, this value is zero.
+    /// header, this value is zero. If the count doesn't fit into this field, this value is also
+    /// zero and the count is in the `size` field of the first section header.
     pub section_header_count: u16,
 
     /// This member holds the index of the string table index. If there is no string table, this
-    /// value is equal to `SHN_UNDEF`.
+    /// value is equal to `SHN_UNDEF`. If the index doesn't fit into this field, this value is
+    /// [FileHeader::EXTENDED_INDEX] and the index is in the `link` field of the first section
+    /// header.
     pub string_table_index: u16,
 }
 
 impl FileHeader {
+    /// This field contains the program header count (`PN_XNUM`) of files, which store the real
+    /// count in the first section header
+    pub const EXTENDED_COUNT: u16 = 0xFFFF;
+
+    /// This field contains the string table index (`SHN_XINDEX`) of files, which store the real
+    /// index in the first section header
+    pub const EXTENDED_INDEX: u16 = 0xFFFF;
+
+    /// This function returns the size of the file header for ELF files with the specified class.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass) -> Result<usize, Error> {
+        match class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => Err(Error::InvalidClass),
+            ElfClass::Class32 => Ok(52),
+            ElfClass::Class64 => Ok(64),
+        }
+    }
+
     /// This function parses the specified slice with the offset to a ELF header. Most parts of the
     /// conversion is done with validation. After a successful parsing, this function returns
     /// the header structure.
     ///
     /// Here is a list with all errors, which can occur while this operation:
     /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the header could be read completely
+    /// - For errors while reading the ident bytes, see [ElfIdent::read]
     pub fn read(slice: &[u8], mut offset: usize) -> Result<FileHeader, Error> {
-        const IDENT_SIZE: usize = mem::size_of::<ElfIdent>();
-
         // Read indication bytes of file header
-        let ident: ElfIdent = unsafe {
-            mem::transmute::<[u8; IDENT_SIZE], ElfIdent>(
-                slice
-                    .get(offset..(offset + IDENT_SIZE))
-                    .unwrap()
-                    .try_into()
-                    .unwrap(),
-            )
-        };
+        let ident = ElfIdent::read(slice, offset)?;
+
+        // Validate that the whole header is in the slice. The offset points behind the magic bytes.
+        sub_slice(slice, offset, Self::size(ident.class)? - 4, "FileHeader")?;
         offset += 12;
 
         // Read platform-independent sized fields
-        let ty = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
-        let machine = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
-        let version = ident.endian.read::<u32>(slice, Some(&mut offset)).unwrap();
+        let ty = ident.endian.read::<u16>(slice, Some(&mut offset))?;
+        let machine = ident.endian.read::<u16>(slice, Some(&mut offset))?;
+        let version = ident.endian.read::<u32>(slice, Some(&mut offset))?;
 
         // Read entrypoint address and some offsets. We also read he size of this header.
         let entry_address = read_class_dependent!(ident, slice, &mut offset);
@@ -200,19 +225,19 @@ impl FileHeader {
         let section_header_offset = read_class_dependent!(ident, slice, &mut offset);
 
         // Read size of this header and flags
-        let flags = ident.endian.read::<u32>(slice, Some(&mut offset)).unwrap();
-        let file_header_size = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
+        let flags = ident.endian.read::<u32>(slice, Some(&mut offset))?;
+        let file_header_size = ident.endian.read::<u16>(slice, Some(&mut offset))?;
 
         // Read count and size of program headers
-        let program_header_size = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
-        let program_header_count = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
+        let program_header_size = ident.endian.read::<u16>(slice, Some(&mut offset))?;
+        let program_header_count = ident.endian.read::<u16>(slice, Some(&mut offset))?;
 
         // Read count and size of section headers
-        let section_header_size = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
-        let section_header_count = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
+        let section_header_size = ident.endian.read::<u16>(slice, Some(&mut offset))?;
+        let section_header_count = ident.endian.read::<u16>(slice, Some(&mut offset))?;
 
         // Read index of string table header
-        let string_table_index = ident.endian.read::<u16>(slice, Some(&mut offset)).unwrap();
+        let string_table_index = ident.endian.read::<u16>(slice, Some(&mut offset))?;
 
         // Create file header and return
         Ok(Self {
@@ -405,42 +430,64 @@ pub struct ProgramHeader {
 }
 
 impl ProgramHeader {
+    /// This function returns the size of a single program header for ELF files with the specified
+    /// class.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass) -> Result<usize, Error> {
+        match class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => Err(Error::InvalidClass),
+            ElfClass::Class32 => Ok(32),
+            ElfClass::Class64 => Ok(56),
+        }
+    }
+
     /// This function reads the data from the section (with offset) and parses it into a
     /// [ProgramHeader] structure for the ELF file.
     ///
     /// Here is a list with all errors, which can occur while this operation:
     /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the header could be read completely
     ///
     /// ## See also
     /// - [Program Header](https://www.sco.com/developers/gabi/latest/ch5.pheader.html) by SCO, Inc.
     pub fn read(ident: &ElfIdent, slice: &[u8], mut offset: usize) -> Result<Self, Error> {
+        sub_slice(slice, offset, Self::size(ident.class)?, "ProgramHeader")?;
         let endian = &ident.endian;
-        let mut program_header = Self::default();
-        program_header.ty =
-            SegmentType::from(ident.endian.read::<u32>(slice, Some(&mut offset)).unwrap());
+        let ty = SegmentType::from(endian.read::<u32>(slice, Some(&mut offset))?);
 
         // Read elf flags if 64-bit ELF
+        let mut flags = SegmentFlags::empty();
         if ident.class == ElfClass::Class64 {
-            program_header.flags =
-                SegmentFlags::from_bits_retain(endian.read(slice, Some(&mut offset)).unwrap());
+            flags = SegmentFlags::from_bits_retain(endian.read(slice, Some(&mut offset))?);
         }
 
         // Read values in center of header
-        program_header.offset = read_class_dependent!(ident, slice, &mut offset);
-        program_header.virtual_address = read_class_dependent!(ident, slice, &mut offset);
-        program_header.physical_address = read_class_dependent!(ident, slice, &mut offset);
-        program_header.file_size = read_class_dependent!(ident, slice, &mut offset);
-        program_header.memory_size = read_class_dependent!(ident, slice, &mut offset);
+        let program_offset = read_class_dependent!(ident, slice, &mut offset);
+        let virtual_address = read_class_dependent!(ident, slice, &mut offset);
+        let physical_address = read_class_dependent!(ident, slice, &mut offset);
+        let file_size = read_class_dependent!(ident, slice, &mut offset);
+        let memory_size = read_class_dependent!(ident, slice, &mut offset);
 
         // Read elf flags if 32-bit ELF
         if ident.class == ElfClass::Class32 {
-            program_header.flags =
-                SegmentFlags::from_bits_retain(endian.read(slice, Some(&mut offset)).unwrap());
+            flags = SegmentFlags::from_bits_retain(endian.read(slice, Some(&mut offset))?);
         }
 
         // Read alignment and return program header
-        program_header.alignment = read_class_dependent!(ident, slice, &mut offset);
-        Ok(program_header)
+        let alignment = read_class_dependent!(ident, slice, &mut offset);
+        Ok(Self {
+            ty,
+            flags,
+            offset: program_offset,
+            virtual_address,
+            physical_address,
+            file_size,
+            memory_size,
+            alignment,
+        })
     }
 
     /// This functions reads the data of the segment by the specified ELF file. The data is returned
@@ -452,13 +499,12 @@ impl ProgramHeader {
     where
         'b: 'a,
     {
-        if elf.bytes.len() - (self.offset as usize) >= (self.file_size as usize) {
-            Ok(&elf.bytes[(self.offset as usize)..((self.offset + self.file_size) as usize)])
-        } else {
-            Err(Error::NotEnoughBytes(
-                (self.offset + self.file_size) as usize,
+        (self.offset as usize)
+            .checked_add(self.file_size as usize)
+            .and_then(|end| elf.bytes.get((self.offset as usize)..end))
+            .ok_or(Error::NotEnoughBytes(
+                self.offset.saturating_add(self.file_size) as usize,
             ))
-        }
     }
 }
 
@@ -484,7 +530,11 @@ pub enum SectionType {
     FiniArray = 15,
     PreInitArray = 16,
     Group = 17,
-    SymbolTableIndex = 81,
+    SymbolTableIndex = 18,
+    GnuHash = 0x6FFFFFF6,
+    VersionDefinition = 0x6FFFFFFD,
+    VersionRequirement = 0x6FFFFFFE,
+    VersionSymbol = 0x6FFFFFFF,
     Unknown(u32),
 }
 
@@ -507,7 +557,11 @@ impl From<u32> for SectionType {
             15 => Self::FiniArray,
             16 => Self::PreInitArray,
             17 => Self::Group,
-            81 => Self::SymbolTableIndex,
+            18 => Self::SymbolTableIndex,
+            0x6FFFFFF6 => Self::GnuHash,
+            0x6FFFFFFD => Self::VersionDefinition,
+            0x6FFFFFFE => Self::VersionRequirement,
+            0x6FFFFFFF => Self::VersionSymbol,
             value => Self::Unknown(value),
         }
     }
@@ -596,29 +650,44 @@ pub struct SectionHeader {
 }
 
 impl SectionHeader {
+    /// This function returns the size of a single section header for ELF files with the specified
+    /// class.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass) -> Result<usize, Error> {
+        match class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => Err(Error::InvalidClass),
+            ElfClass::Class32 => Ok(40),
+            ElfClass::Class64 => Ok(64),
+        }
+    }
+
     /// This function reads the data from the section (with offset) and parses it into a
     /// [SectionHeader] structure for the ELF file.
     ///
     /// Here is a list with all errors, which can occur while this operation:
     /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the header could be read completely
     ///
     /// ## See also
-    /// - [Program Header](https://www.sco.com/developers/gabi/latest/ch5.pheader.html) by SCO, Inc.
+    /// - [Sections](https://www.sco.com/developers/gabi/latest/ch4.sheader.html) by SCO, Inc.
     pub fn read(ident: &ElfIdent, slice: &[u8], mut offset: usize) -> Result<Self, Error> {
+        sub_slice(slice, offset, Self::size(ident.class)?, "SectionHeader")?;
         let endian = &ident.endian;
-        let mut program_header = Self::default();
-        program_header.name = endian.read::<u32>(slice, Some(&mut offset)).unwrap();
-        program_header.ty = SectionType::from(endian.read::<u32>(slice, Some(&mut offset)).unwrap());
-        program_header.flags =
-            SectionFlags::from_bits_retain(read_class_dependent!(ident, slice, &mut offset));
-        program_header.addr = read_class_dependent!(ident, slice, &mut offset);
-        program_header.offset = read_class_dependent!(ident, slice, &mut offset);
-        program_header.size = read_class_dependent!(ident, slice, &mut offset);
-        program_header.link = endian.read::<u32>(slice, Some(&mut offset)).unwrap();
-        program_header.info = endian.read::<u32>(slice, Some(&mut offset)).unwrap();
-        program_header.addr_align = read_class_dependent!(ident, slice, &mut offset);
-        program_header.entry_size = read_class_dependent!(ident, slice, &mut offset);
-        Ok(program_header)
+        Ok(Self {
+            name: endian.read::<u32>(slice, Some(&mut offset))?,
+            ty: SectionType::from(endian.read::<u32>(slice, Some(&mut offset))?),
+            flags: SectionFlags::from_bits_retain(read_class_dependent!(ident, slice, &mut offset)),
+            addr: read_class_dependent!(ident, slice, &mut offset),
+            offset: read_class_dependent!(ident, slice, &mut offset),
+            size: read_class_dependent!(ident, slice, &mut offset),
+            link: endian.read::<u32>(slice, Some(&mut offset))?,
+            info: endian.read::<u32>(slice, Some(&mut offset))?,
+            addr_align: read_class_dependent!(ident, slice, &mut offset),
+            entry_size: read_class_dependent!(ident, slice, &mut offset),
+        })
     }
 
     /// This functions reads the data of the section by the specified ELF file. The data is returned
@@ -630,14 +699,15 @@ impl SectionHeader {
     where
         'b: 'a,
     {
-        if elf.bytes.len() - (self.offset as usize) >= (self.size as usize) {
-            Ok(if self.ty != SectionType::NoBits {
-                &elf.bytes[(self.offset as usize)..((self.offset + self.size) as usize)]
-            } else {
-                &[]
-            })
-        } else {
-            Err(Error::NotEnoughBytes((self.offset + self.size) as usize))
+        if self.ty == SectionType::NoBits {
+            return Ok(&[]);
         }
+
+        (self.offset as usize)
+            .checked_add(self.size as usize)
+            .and_then(|end| elf.bytes.get((self.offset as usize)..end))
+            .ok_or(Error::NotEnoughBytes(
+                self.offset.saturating_add(self.size) as usize
+            ))
     }
 }
@@ -2,11 +2,26 @@
 
 extern crate alloc;
 
+pub mod compression;
+pub mod debug;
+pub mod dynamic;
+pub mod embedded;
 pub mod endian;
+#[cfg(feature = "std")] pub mod file;
+pub mod group;
+pub mod hash;
 pub mod header;
+pub mod note;
+#[cfg(feature = "std")] pub mod reader;
+pub mod relocation;
+pub mod security;
+pub mod string_table;
+pub mod symbol;
 #[cfg(test)] pub mod tests;
+pub mod version;
 
 use compile_warning::compile_warning;
+use spin::Once;
 use thiserror_no_std::Error;
 
 #[cfg(feature = "std")] pub use std;
@@ -14,14 +29,18 @@ use thiserror_no_std::Error;
 #[cfg(not(feature = "std"))] use alloc::vec::Vec;
 
 use crate::{
+    compression::CompressionType,
     header::{
         ident::ElfIdent,
         FileHeader,
         ProgramHeader,
         SectionHeader,
+        SegmentType,
     },
-    std::mem::size_of,
+    string_table::StringTable,
+    symbol::SymbolIndex,
 };
+
 #[cfg(not(feature = "std"))] pub use core as std;
 
 // Inform a potential user that this library is not intended for use in production environments.
@@ -50,52 +69,158 @@ pub enum Error {
     /// The provided ELF file's class is not valid
     #[error("The provided ELF file's class is not valid")]
     InvalidClass,
+
+    /// The provided ELF file's endianness is not valid
+    #[error("The provided ELF file's endianness is not valid")]
+    InvalidEndian,
+
+    /// The provided ELF file's version in the ident bytes is not the current version
+    #[error("The provided ELF file's version is not valid")]
+    InvalidVersion,
+
+    /// The padding bytes of the provided ELF file's ident bytes are not zero
+    #[error("The provided ELF file's ident padding is not zero")]
+    InvalidPadding,
+
+    /// The specified data ends before the structure (first field) at the offset (second field) could
+    /// be read completely
+    #[error("Unable to read {0} at offset 0x{1:X}, because the data ends before the structure")]
+    UnexpectedEnd(&'static str, usize),
+
+    /// The string at the specified index of a string table is not valid UTF-8
+    #[error("The string at index {0} of the string table is not valid UTF-8")]
+    InvalidString(usize),
+
+    /// The specified section index is not in the section header table
+    #[error("The section index {0} is not in the section header table")]
+    InvalidSectionIndex(usize),
+
+    /// The specified segment index is not in the program header table
+    #[error("The segment index {0} is not in the program header table")]
+    InvalidSegmentIndex(usize),
+
+    /// The ELF file doesn't contain the specified section
+    #[error("The ELF file doesn't contain a {0} section")]
+    MissingSection(&'static str),
+
+    /// The dynamic section doesn't contain the specified entry
+    #[error("The dynamic section doesn't contain a {0} entry")]
+    MissingDynamicEntry(&'static str),
+
+    /// The specified virtual address is not mapped by a loadable segment
+    #[error("The virtual address 0x{0:X} is not mapped by a loadable segment")]
+    UnmappedAddress(u64),
+
+    /// The specified virtual address is in the zero-filled part of a loadable segment, so there is
+    /// no data for the address in the ELF file
+    #[error("The virtual address 0x{0:X} is in the zero-filled part of a loadable segment")]
+    ZeroFilledAddress(u64),
+
+    /// The specified file offset is not mapped by a loadable segment
+    #[error("The file offset 0x{0:X} is not mapped by a loadable segment")]
+    UnmappedOffset(u64),
+
+    /// The compression algorithm of a section is unknown or its feature is disabled
+    #[error("The compression algorithm {0:?} is not supported, maybe the feature is disabled")]
+    UnsupportedCompression(CompressionType),
+
+    /// The compressed data of a section can't be decompressed or has an unexpected size
+    #[error("The compressed data of the section is invalid")]
+    InvalidCompressedData,
+
+    /// The specified program header size of the file header doesn't match the ELF class
+    #[error("The program header size {0} doesn't match the class of the ELF file")]
+    InvalidProgramHeaderSize(u16),
+
+    /// The specified section header size of the file header doesn't match the ELF class
+    #[error("The section header size {0} doesn't match the class of the ELF file")]
+    InvalidSectionHeaderSize(u16),
+
+    /// The program header table at the offset (first field) with the count of entries (second
+    /// field) ends outside of the specified ELF data
+    #[error("The program header table at offset 0x{0:X} with {1} entries exceeds the ELF data")]
+    ProgramHeaderTableOutOfBounds(u64, usize),
+
+    /// The section header table at the offset (first field) with the count of entries (second
+    /// field) ends outside of the specified ELF data
+    #[error("The section header table at offset 0x{0:X} with {1} entries exceeds the ELF data")]
+    SectionHeaderTableOutOfBounds(u64, usize),
+
+    /// The specified section header string table index is not in the section header table
+    #[error("The section header string table index {0} is not in the section header table")]
+    InvalidStringTableIndex(usize),
+
+    /// The file header specifies the count of section headers, but no section header table offset
+    #[error("The file header specifies {0} section headers, but no section header table offset")]
+    MissingSectionHeaderTable(usize),
+
+    /// The specified bloom filter shift of a GNU hash table is not lower than the hash width
+    #[error("The bloom filter shift {0} of the GNU hash table is not lower than 32")]
+    InvalidBloomShift(u32),
 }
 
 pub struct Elf<'a> {
     header: FileHeader,
     program_headers: Option<Vec<ProgramHeader>>,
     section_headers: Option<Vec<SectionHeader>>,
+    string_table_index: usize,
     bytes: &'a [u8],
+    symbol_index: Once<SymbolIndex<'a>>,
 }
 
 impl<'a> Elf<'a> {
     /// This field contains the magic bytes of an ELF file
-    const MAGIC_BYTES: [u8; 4] = [0x7F, 0x45, 0x4C, 0x46];
+    pub(crate) const MAGIC_BYTES: [u8; 4] = [0x7F, 0x45, 0x4C, 0x46];
 
     /// This field contains the minimal size of an ELF file
-    const MIN_ELF_SIZE: usize = size_of::<ElfIdent>();
+    const MIN_ELF_SIZE: usize = ElfIdent::SIZE;
 
     /// This function accepts a byte slice and parses it into the content of the ELF file. But this
     /// conversion can fail, if the validation of the values in the header or other section data is
-    /// invalid.
+    /// invalid. The magic bytes must be at the start of the slice, for ELF files inside of other
+    /// data, see [Elf::find_embedded].
     ///
     /// Here is a list with all errors, which can occur while this operation:
-    /// - [Error::InvalidMagic] - The magic bytes of the file can't be found
+    /// - [Error::InvalidMagic] - The slice doesn't start with the magic bytes
     /// - [Error::NotEnoughBytes] - The specified ELF data's size is not high enough to be a ELF file
     /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::InvalidVersion] - The provided ELF file's version is not the current version
+    /// - [Error::InvalidPadding] - The padding bytes of the ident bytes are not zero
+    /// - [Error::UnexpectedEnd] - A header ends outside of the specified ELF data
+    /// - [Error::InvalidProgramHeaderSize] - The program header size doesn't match the ELF class
+    /// - [Error::InvalidSectionHeaderSize] - The section header size doesn't match the ELF class
+    /// - [Error::ProgramHeaderTableOutOfBounds] - The program header table exceeds the ELF data
+    /// - [Error::SectionHeaderTableOutOfBounds] - The section header table exceeds the ELF data
+    /// - [Error::InvalidStringTableIndex] - The section header string table index is not in the
+    ///   section header table
+    /// - [Error::MissingSectionHeaderTable] - The count of section headers is not zero, but the
+    ///   offset of the section header table is zero
     pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
-        // Get index of ELF header and validate size of the file with magic bytes index as start
-        // point
-        let index = Self::elf_index(bytes).ok_or(Error::InvalidMagic)? + 4;
-        if (bytes.len() - index) < Self::MIN_ELF_SIZE {
-            return Err(Error::NotEnoughBytes(bytes.len() - index));
+        // Validate the magic bytes at the start and the size of the file behind the magic bytes
+        if !bytes.starts_with(&Self::MAGIC_BYTES) {
+            return Err(Error::InvalidMagic);
+        }
+        if (bytes.len() - Self::MAGIC_BYTES.len()) < Self::MIN_ELF_SIZE {
+            return Err(Error::NotEnoughBytes(bytes.len() - Self::MAGIC_BYTES.len()));
         }
 
         // Read ELF header
-        let header = FileHeader::read(bytes, index.clone())?;
+        let header = FileHeader::read(bytes, Self::MAGIC_BYTES.len())?;
+
+        // Validate the header tables and resolve the extended numbering
+        let (program_header_count, section_header_count, string_table_index) =
+            Self::header_table_layout(&header, bytes.len(), |offset| {
+                SectionHeader::read(&header.ident, bytes, offset)
+            })?;
 
         // Read all program headers
-        let program_headers = if header.program_header_count > 0 {
-            let mut program_headers = Vec::new();
-            for i in 0..header.section_header_count {
-                program_headers.push(ProgramHeader::read(
-                    &header.ident,
-                    bytes,
-                    index - 4
-                        + header.program_header_offset as usize
-                        + (i * header.program_header_size) as usize,
-                )?);
+        let program_headers = if program_header_count > 0 {
+            let mut program_headers = Vec::with_capacity(program_header_count);
+            for i in 0..program_header_count {
+                let offset =
+                    header.program_header_offset as usize + i * header.program_header_size as usize;
+                program_headers.push(ProgramHeader::read(&header.ident, bytes, offset)?);
             }
             Some(program_headers)
         } else {
@@ -103,16 +228,12 @@ impl<'a> Elf<'a> {
         };
 
         // Read all section headers
-        let section_headers = if header.section_header_count > 0 {
-            let mut section_headers = Vec::new();
-            for i in 0..header.section_header_count {
-                section_headers.push(SectionHeader::read(
-                    &header.ident,
-                    bytes,
-                    index - 4
-                        + header.section_header_offset as usize
-                        + (i * header.section_header_size) as usize,
-                )?);
+        let section_headers = if section_header_count > 0 {
+            let mut section_headers = Vec::with_capacity(section_header_count);
+            for i in 0..section_header_count {
+                let offset =
+                    header.section_header_offset as usize + i * header.section_header_size as usize;
+                section_headers.push(SectionHeader::read(&header.ident, bytes, offset)?);
             }
             Some(section_headers)
         } else {
@@ -124,20 +245,154 @@ impl<'a> Elf<'a> {
             header,
             program_headers,
             section_headers,
-            bytes: &bytes[(index - 4)..bytes.len()],
+            string_table_index,
+            bytes,
+            symbol_index: Once::new(),
         })
     }
 
+    /// This function validates the header tables of the specified file header for ELF data with the
+    /// specified length and returns the count of program headers, the count of section headers
+    /// and the section header string table index. If the file uses extended numbering, the first
+    /// section header is read with the specified function at the specified offset.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidProgramHeaderSize] - The program header size doesn't match the ELF class
+    /// - [Error::InvalidSectionHeaderSize] - The section header size doesn't match the ELF class
+    /// - [Error::ProgramHeaderTableOutOfBounds] - The program header table exceeds the ELF data
+    /// - [Error::SectionHeaderTableOutOfBounds] - The section header table exceeds the ELF data
+    /// - [Error::InvalidStringTableIndex] - The section header string table index is not in the
+    ///   section header table
+    /// - [Error::MissingSectionHeaderTable] - The count of section headers is not zero, but the
+    ///   offset of the section header table is zero
+    pub(crate) fn header_table_layout<F>(
+        header: &FileHeader, length: usize, read_first: F,
+    ) -> Result<(usize, usize, usize), Error>
+    where
+        F: FnOnce(usize) -> Result<SectionHeader, Error>,
+    {
+        // The section header table can't be at the start of the file, which contains the file header
+        if header.section_header_offset == 0 && header.section_header_count > 0 {
+            return Err(Error::MissingSectionHeaderTable(
+                header.section_header_count as usize,
+            ));
+        }
+        let validate_section_header_size = || {
+            if header.section_header_size as usize != SectionHeader::size(header.ident.class)? {
+                return Err(Error::InvalidSectionHeaderSize(header.section_header_size));
+            }
+            Ok(())
+        };
+
+        // Read the first section header, which contains the real counts and string table index of
+        // files with extended numbering
+        let extended = header.program_header_count == FileHeader::EXTENDED_COUNT
+            || header.section_header_count == 0
+            || header.string_table_index == FileHeader::EXTENDED_INDEX;
+        let first = match header.section_header_offset {
+            0 => None,
+            offset if extended => {
+                validate_section_header_size()?;
+                Some(read_first(offset as usize)?)
+            }
+            _ => None,
+        };
+        let program_header_count = match (header.program_header_count, &first) {
+            (FileHeader::EXTENDED_COUNT, Some(first)) => first.info as usize,
+            (count, _) => count as usize,
+        };
+        let section_header_count = match (header.section_header_count, &first) {
+            (0, Some(first)) => first.size as usize,
+            (count, _) => count as usize,
+        };
+        let string_table_index = match (header.string_table_index, &first) {
+            (FileHeader::EXTENDED_INDEX, Some(first)) => first.link as usize,
+            (index, _) => index as usize,
+        };
+
+        // Validate the entry size and bounds of the program header table
+        if program_header_count > 0 {
+            if header.program_header_size as usize != ProgramHeader::size(header.ident.class)? {
+                return Err(Error::InvalidProgramHeaderSize(header.program_header_size));
+            }
+            if !Self::table_fits(
+                length,
+                header.program_header_offset,
+                program_header_count,
+                header.program_header_size,
+            ) {
+                return Err(Error::ProgramHeaderTableOutOfBounds(
+                    header.program_header_offset,
+                    program_header_count,
+                ));
+            }
+        }
+
+        // Validate the entry size and bounds of the section header table and the string table index
+        if section_header_count > 0 {
+            validate_section_header_size()?;
+            if !Self::table_fits(
+                length,
+                header.section_header_offset,
+                section_header_count,
+                header.section_header_size,
+            ) {
+                return Err(Error::SectionHeaderTableOutOfBounds(
+                    header.section_header_offset,
+                    section_header_count,
+                ));
+            }
+            if string_table_index >= section_header_count {
+                return Err(Error::InvalidStringTableIndex(string_table_index));
+            }
+        }
+        Ok((
+            program_header_count,
+            section_header_count,
+            string_table_index,
+        ))
+    }
+
+    /// This function returns whether the header table with the specified offset, entry count and
+    /// entry size is completely in ELF data with the specified length.
+    fn table_fits(length: usize, offset: u64, count: usize, entry_size: u16) -> bool {
+        usize::try_from(offset)
+            .ok()
+            .zip(count.checked_mul(entry_size as usize))
+            .and_then(|(offset, size)| offset.checked_add(size))
+            .map_or(false, |end| end <= length)
+    }
+
+    /// This function searches the first ELF file inside of the specified data (like a firmware
+    /// image) and returns the offset of the ELF file in the data with the parsed ELF file. All
+    /// occurrences of the magic bytes are tried in order, until one of them can be parsed.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidMagic] - The magic bytes can't be found in the specified data
+    /// - For errors of the first occurrence of the magic bytes, see [Elf::from_bytes]
+    pub fn find_embedded(bytes: &'a [u8]) -> Result<(usize, Self), Error> {
+        let mut error = None;
+        let mut offset = 0;
+        while let Some(index) = Self::elf_index(&bytes[offset..]) {
+            offset += index;
+            match Self::from_bytes(&bytes[offset..]) {
+                Ok(elf) => return Ok((offset, elf)),
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+            offset += 1;
+        }
+        Err(error.unwrap_or(Error::InvalidMagic))
+    }
+
     /// This function scans the specified data for the ELF magic bytes. If no magic bytes are found
     /// the function returns a None. Otherwise this function returns the index of the magic bytes in
     /// the specified data.
     fn elf_index(bytes: &[u8]) -> Option<usize> {
-        for i in 0..(bytes.len() - Self::MAGIC_BYTES.len()) {
-            if bytes[i..=(i + 3)].eq(Self::MAGIC_BYTES.as_slice()) {
-                return Some(i);
-            }
-        }
-        None
+        bytes
+            .windows(Self::MAGIC_BYTES.len())
+            .position(|window| window == Self::MAGIC_BYTES.as_slice())
     }
 
     /// This function returns a reference to the file header.
@@ -155,4 +410,203 @@ impl<'a> Elf<'a> {
     pub const fn section_headers(&self) -> Option<&Vec<SectionHeader>> {
         self.section_headers.as_ref()
     }
+
+    /// This function returns the index and header of the loadable segment, which maps the specified
+    /// virtual address into the memory. The memory size of the segment is used, so this includes
+    /// the zero-filled part of the segment. If no segment maps the address, this function returns
+    /// None.
+    pub fn segment_at_vaddr(&self, address: u64) -> Option<(usize, &ProgramHeader)> {
+        self.program_headers
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, program_header)| program_header.ty == SegmentType::Load)
+            .find(|(_, program_header)| {
+                address >= program_header.virtual_address
+                    && address - program_header.virtual_address < program_header.memory_size
+            })
+    }
+
+    /// This function translates the specified virtual address to the offset in the ELF data with
+    /// the loadable segments. This function returns the offset and the index of the segment, which
+    /// maps the address.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnmappedAddress] - The address is not mapped by a loadable segment
+    /// - [Error::ZeroFilledAddress] - The address is in the zero-filled part of the segment (e.g.
+    ///   `.bss`), which has no data in the ELF file
+    pub fn vaddr_to_offset(&self, address: u64) -> Result<(u64, usize), Error> {
+        let (index, program_header) = self
+            .segment_at_vaddr(address)
+            .ok_or(Error::UnmappedAddress(address))?;
+        let segment_offset = address - program_header.virtual_address;
+        if segment_offset >= program_header.file_size {
+            return Err(Error::ZeroFilledAddress(address));
+        }
+        let offset = program_header
+            .offset
+            .checked_add(segment_offset)
+            .ok_or(Error::UnmappedAddress(address))?;
+        Ok((offset, index))
+    }
+
+    /// This function translates the specified offset in the ELF data to the virtual address with
+    /// the loadable segments. This function returns the virtual address and the index of the
+    /// segment, which maps the offset.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnmappedOffset] - The offset is not mapped by a loadable segment
+    pub fn offset_to_vaddr(&self, offset: u64) -> Result<(u64, usize), Error> {
+        self.program_headers
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, program_header)| program_header.ty == SegmentType::Load)
+            .find(|(_, program_header)| {
+                offset >= program_header.offset
+                    && offset - program_header.offset < program_header.file_size
+            })
+            .and_then(|(index, program_header)| {
+                program_header
+                    .virtual_address
+                    .checked_add(offset - program_header.offset)
+                    .map(|address| (address, index))
+            })
+            .ok_or(Error::UnmappedOffset(offset))
+    }
+
+    /// This function reads the data with the specified size at the specified virtual address. The
+    /// data must be in the file part of a single loadable segment.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnmappedAddress] - The data is not mapped by a single loadable segment
+    /// - [Error::ZeroFilledAddress] - The data reaches into the zero-filled part of the segment
+    /// - [Error::UnexpectedEnd] - The data of the segment is not in the ELF data
+    pub fn read_at_vaddr(&self, address: u64, size: usize) -> Result<&'a [u8], Error> {
+        let (_, program_header) = self
+            .segment_at_vaddr(address)
+            .ok_or(Error::UnmappedAddress(address))?;
+        let segment_offset = address - program_header.virtual_address;
+        let end = segment_offset.saturating_add(size as u64);
+        if end > program_header.memory_size {
+            return Err(Error::UnmappedAddress(
+                program_header
+                    .virtual_address
+                    .saturating_add(program_header.memory_size),
+            ));
+        }
+        if end > program_header.file_size {
+            return Err(Error::ZeroFilledAddress(
+                address.max(
+                    program_header
+                        .virtual_address
+                        .saturating_add(program_header.file_size),
+                ),
+            ));
+        }
+
+        let offset = program_header
+            .offset
+            .checked_add(segment_offset)
+            .ok_or(Error::UnmappedAddress(address))?;
+        endian::sub_slice(self.bytes, offset as usize, size, "segment data")
+    }
+
+    /// This function reads the data from the specified virtual address to the end of the file
+    /// part of the loadable segment. This is used for tables, which have no recorded size.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnmappedAddress] - The address is not mapped by a loadable segment
+    /// - [Error::ZeroFilledAddress] - The address is in the zero-filled part of the segment
+    /// - [Error::UnexpectedEnd] - The data of the segment is not in the ELF data
+    pub(crate) fn read_to_segment_end(&self, address: u64) -> Result<&'a [u8], Error> {
+        let (_, program_header) = self
+            .segment_at_vaddr(address)
+            .ok_or(Error::UnmappedAddress(address))?;
+        let segment_offset = address - program_header.virtual_address;
+        if segment_offset >= program_header.file_size {
+            return Err(Error::ZeroFilledAddress(address));
+        }
+
+        let offset = program_header
+            .offset
+            .checked_add(segment_offset)
+            .ok_or(Error::UnmappedAddress(address))?;
+        endian::sub_slice(
+            self.bytes,
+            offset as usize,
+            (program_header.file_size - segment_offset) as usize,
+            "segment data",
+        )
+    }
+
+    /// This function returns the section header at the specified index in the section header
+    /// table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The index is not in the section header table
+    pub fn section_header(&self, index: usize) -> Result<&SectionHeader, Error> {
+        self.section_headers
+            .as_ref()
+            .and_then(|section_headers| section_headers.get(index))
+            .ok_or(Error::InvalidSectionIndex(index))
+    }
+
+    /// This function returns a view over the string table in the section at the specified index in
+    /// the section header table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The index is not in the section header table
+    /// - [Error::NotEnoughBytes] - The section's data is not in the ELF data
+    pub fn string_table(&self, index: usize) -> Result<StringTable<'a>, Error> {
+        Ok(StringTable::new(self.section_header(index)?.data(self)?))
+    }
+
+    /// This function returns a view over the section header string table, which contains the names
+    /// of all sections. The index of this table is specified in the file header or, with extended
+    /// numbering, in the first section header.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no section header string table
+    /// - [Error::InvalidSectionIndex] - The index is not in the section header table
+    /// - [Error::NotEnoughBytes] - The section's data is not in the ELF data
+    pub fn section_header_string_table(&self) -> Result<StringTable<'a>, Error> {
+        match self.string_table_index {
+            0 => Err(Error::MissingSection(".shstrtab")),
+            index => self.string_table(index),
+        }
+    }
+
+    /// This function resolves the name of the specified section with the section header string
+    /// table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no section header string table
+    /// - [Error::InvalidSectionIndex] - The string table index is not in the section header table
+    /// - [Error::NotEnoughBytes] - The string table's data is not in the ELF data
+    /// - [Error::UnexpectedEnd] - The name is not in the string table
+    /// - [Error::InvalidString] - The name is not valid UTF-8
+    pub fn section_name(&self, section_header: &SectionHeader) -> Result<&'a str, Error> {
+        self.section_header_string_table()?
+            .get(section_header.name as usize)
+    }
+
+    /// This function returns the first section header with the specified name. If there is no
+    /// section with that name or the names can't be resolved, this function returns None.
+    pub fn section_by_name(&self, name: &str) -> Option<&SectionHeader> {
+        self.sections()
+            .filter_map(Result::ok)
+            .find(|(section_name, _)| *section_name == name)
+            .map(|(_, section_header)| section_header)
+    }
+
+    /// This function returns an iterator over all section headers with their names. The name of
+    /// each section is resolved with the section header string table, so an item is an error if
+    /// the name of the section can't be resolved.
+    pub fn sections(&self) -> impl Iterator<Item = Result<(&'a str, &SectionHeader), Error>> {
+        self.section_headers
+            .iter()
+            .flatten()
+            .map(|section_header| Ok((self.section_name(section_header)?, section_header)))
+    }
 }
@@ -0,0 +1,271 @@
+use crate::{
+    endian::sub_slice,
+    header::{
+        ident::ElfIdent,
+        ProgramHeader,
+        SectionHeader,
+        SectionType,
+        SegmentType,
+    },
+    std::str,
+    Elf,
+    Error,
+};
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+pub mod property;
+
+/// This structure represents the ABI tag of a `NT_GNU_ABI_TAG` note. The tag specifies the operating
+/// system and the earliest version of the operating system's ABI, which is supported by the file.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub struct GnuAbiTag {
+    /// This field indicates the operating system (0 = Linux, 1 = GNU Hurd, 2 = Solaris, 3 =
+    /// FreeBSD).
+    pub os: u32,
+
+    /// This field indicates the major version of the ABI.
+    pub major: u32,
+
+    /// This field indicates the minor version of the ABI.
+    pub minor: u32,
+
+    /// This field indicates the subminor version of the ABI.
+    pub subminor: u32,
+}
+
+/// This enum represents the decoded content of a note. Only common notes of the `GNU` owner are
+/// decoded, all other notes are [NoteKind::Unknown].
+///
+/// - [NoteKind::GnuAbiTag]: ABI tag of the file (`NT_GNU_ABI_TAG`)
+/// - [NoteKind::GnuBuildId]: Unique build ID of the file (`NT_GNU_BUILD_ID`)
+/// - [NoteKind::GnuGoldVersion]: Version of the gold linker (`NT_GNU_GOLD_VERSION`)
+/// - [NoteKind::GnuProperty]: Raw program properties (`NT_GNU_PROPERTY_TYPE_0`), see
+///   [property::GnuProperty]
+/// - [NoteKind::Unknown]: Unknown note
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum NoteKind<'a> {
+    GnuAbiTag(GnuAbiTag),
+    GnuBuildId(&'a [u8]),
+    GnuGoldVersion(&'a str),
+    GnuProperty(&'a [u8]),
+    Unknown,
+}
+
+/// This structure represents a single note of a `SHT_NOTE` section or `PT_NOTE` segment in an ELF
+/// file. The note is identified by the name of the owner and the type and contains a descriptor
+/// with the owner-specific data.
+///
+/// ## See also
+/// - [Note Section](https://www.sco.com/developers/gabi/latest/ch5.pheader.html#note_section) by SCO,
+///   Inc.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct Note<'a> {
+    /// This field indicates the name of the owner of the note without the null terminator.
+    pub name: &'a str,
+
+    /// This field indicates the owner-specific type of the note.
+    pub ty: u32,
+
+    /// This field contains the owner-specific descriptor of the note.
+    pub desc: &'a [u8],
+
+    ident: ElfIdent,
+}
+
+impl<'a> Note<'a> {
+    /// This field contains the type of the `NT_GNU_ABI_TAG` note
+    pub const GNU_ABI_TAG: u32 = 1;
+
+    /// This field contains the type of the `NT_GNU_BUILD_ID` note
+    pub const GNU_BUILD_ID: u32 = 3;
+
+    /// This field contains the type of the `NT_GNU_GOLD_VERSION` note
+    pub const GNU_GOLD_VERSION: u32 = 4;
+
+    /// This field contains the type of the `NT_GNU_PROPERTY_TYPE_0` note
+    pub const GNU_PROPERTY_TYPE_0: u32 = 5;
+
+    /// This function reads the note at the specified offset of the slice. The name and descriptor
+    /// are padded to 8 bytes, if the alignment is 8. Otherwise they're padded to 4 bytes. After a
+    /// successful parsing, this function returns the note and the offset of the next note.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the note could be read completely
+    /// - [Error::InvalidString] - The name of the note is not valid UTF-8
+    pub fn read(
+        ident: &ElfIdent, slice: &'a [u8], mut offset: usize, alignment: u64,
+    ) -> Result<(Self, usize), Error> {
+        let alignment = if alignment == 8 { 8 } else { 4 };
+        let start = offset;
+        let name_size = ident.endian.read::<u32>(slice, Some(&mut offset))? as usize;
+        let desc_size = ident.endian.read::<u32>(slice, Some(&mut offset))? as usize;
+        let ty = ident.endian.read::<u32>(slice, Some(&mut offset))?;
+
+        // Read name without null terminator
+        let name = sub_slice(slice, offset, name_size, "Note")?;
+        let name = name.strip_suffix(&[0]).unwrap_or(name);
+        let name = str::from_utf8(name).map_err(|_| Error::InvalidString(start))?;
+        offset = align(offset + name_size, alignment);
+
+        // Read descriptor
+        let desc = sub_slice(slice, offset, desc_size, "Note")?;
+        offset = align(offset + desc_size, alignment);
+        Ok((
+            Self {
+                name,
+                ty,
+                desc,
+                ident: *ident,
+            },
+            offset,
+        ))
+    }
+
+    /// This function decodes the descriptor of this note. For more information, see [NoteKind].
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The descriptor is too short for the type of the note
+    /// - [Error::InvalidString] - The version of the gold linker is not valid UTF-8
+    pub fn kind(&self) -> Result<NoteKind<'a>, Error> {
+        if self.name != "GNU" {
+            return Ok(NoteKind::Unknown);
+        }
+
+        let endian = &self.ident.endian;
+        Ok(match self.ty {
+            Self::GNU_ABI_TAG => {
+                let mut offset = 0;
+                NoteKind::GnuAbiTag(GnuAbiTag {
+                    os: endian.read(self.desc, Some(&mut offset))?,
+                    major: endian.read(self.desc, Some(&mut offset))?,
+                    minor: endian.read(self.desc, Some(&mut offset))?,
+                    subminor: endian.read(self.desc, Some(&mut offset))?,
+                })
+            }
+            Self::GNU_BUILD_ID => NoteKind::GnuBuildId(self.desc),
+            Self::GNU_GOLD_VERSION => {
+                let version = self.desc.split(|byte| *byte == 0).next().unwrap_or(&[]);
+                NoteKind::GnuGoldVersion(
+                    str::from_utf8(version).map_err(|_| Error::InvalidString(0))?,
+                )
+            }
+            Self::GNU_PROPERTY_TYPE_0 => NoteKind::GnuProperty(self.desc),
+            _ => NoteKind::Unknown,
+        })
+    }
+}
+
+/// This function aligns the specified offset upwards to the specified alignment.
+#[inline]
+const fn align(offset: usize, alignment: usize) -> usize {
+    (offset + alignment - 1) & !(alignment - 1)
+}
+
+/// This structure represents an iterator over all notes in the data of a `SHT_NOTE` section or
+/// `PT_NOTE` segment. If a note can't be read, the iterator returns the error and ends.
+#[derive(Clone, Copy, Debug)]
+pub struct NoteIter<'a> {
+    ident: ElfIdent,
+    bytes: &'a [u8],
+    alignment: u64,
+    offset: usize,
+}
+
+impl<'a> NoteIter<'a> {
+    /// This function creates an iterator over the notes in the specified bytes. The name and
+    /// descriptor of the notes are padded to 8 bytes, if the alignment is 8. Otherwise they're
+    /// padded to 4 bytes.
+    #[inline]
+    pub const fn new(ident: ElfIdent, bytes: &'a [u8], alignment: u64) -> Self {
+        Self {
+            ident,
+            bytes,
+            alignment,
+            offset: 0,
+        }
+    }
+
+    /// This function creates an iterator over the notes in the specified section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the section is not in the ELF data
+    pub fn from_section(elf: &Elf<'a>, section_header: &SectionHeader) -> Result<Self, Error> {
+        Ok(Self::new(
+            elf.header.ident,
+            section_header.data(elf)?,
+            section_header.addr_align,
+        ))
+    }
+
+    /// This function creates an iterator over the notes in the specified segment.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the segment is not in the ELF data
+    pub fn from_segment(elf: &Elf<'a>, program_header: &ProgramHeader) -> Result<Self, Error> {
+        Ok(Self::new(
+            elf.header.ident,
+            program_header.data(elf)?,
+            program_header.alignment,
+        ))
+    }
+}
+
+impl<'a> Iterator for NoteIter<'a> {
+    type Item = Result<Note<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+
+        match Note::read(&self.ident, self.bytes, self.offset, self.alignment) {
+            Ok((note, offset)) => {
+                self.offset = offset;
+                Some(Ok(note))
+            }
+            Err(error) => {
+                self.offset = self.bytes.len();
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns all notes of the ELF file. The notes are read from the `PT_NOTE`
+    /// segments or, if there are no such segments, from the `SHT_NOTE` sections.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of a segment or section is not in the ELF data
+    /// - [Error::UnexpectedEnd] - A note ends outside of the segment or section
+    /// - [Error::InvalidString] - The name of a note is not valid UTF-8
+    pub fn notes(&self) -> Result<Vec<Note<'a>>, Error> {
+        let mut notes = Vec::new();
+        for program_header in self
+            .program_headers
+            .iter()
+            .flatten()
+            .filter(|program_header| program_header.ty == SegmentType::Note)
+        {
+            for note in NoteIter::from_segment(self, program_header)? {
+                notes.push(note?);
+            }
+        }
+
+        if notes.is_empty() {
+            for section_header in self
+                .section_headers
+                .iter()
+                .flatten()
+                .filter(|section_header| section_header.ty == SectionType::Note)
+            {
+                for note in NoteIter::from_section(self, section_header)? {
+                    notes.push(note?);
+                }
+            }
+        }
+        Ok(notes)
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    endian::sub_slice,
+    header::{
+        ident::{
+            ElfClass,
+            ElfIdent,
+        },
+        TargetMachine,
+    },
+    note::NoteKind,
+    Elf,
+    Error,
+};
+use bitflags::bitflags;
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+bitflags! {
+    /// This structure contains the x86 ISA levels of the `GNU_PROPERTY_X86_ISA_1_USED` and
+    /// `GNU_PROPERTY_X86_ISA_1_NEEDED` properties.
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct X86IsaLevel: u32 {
+        /// The x86-64 baseline ISA (CMOV, CX8, FPU, FXSR, MMX, OSFXSR, SCE, SSE, SSE2)
+        const BASELINE = 0x1;
+
+        /// The x86-64-v2 ISA level (CMPXCHG16B, LAHF-SAHF, POPCNT, SSE3, SSE4.1, SSE4.2, SSSE3)
+        const V2       = 0x2;
+
+        /// The x86-64-v3 ISA level (AVX, AVX2, BMI1, BMI2, F16C, FMA, LZCNT, MOVBE, XSAVE)
+        const V3       = 0x4;
+
+        /// The x86-64-v4 ISA level (AVX512F, AVX512BW, AVX512CD, AVX512DQ, AVX512VL)
+        const V4       = 0x8;
+    }
+}
+
+bitflags! {
+    /// This structure contains the x86 features of the `GNU_PROPERTY_X86_FEATURE_1_AND` property.
+    /// These features are used for the Intel Control-flow Enforcement Technology (CET).
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct X86Features: u32 {
+        /// The file is compatible with Indirect Branch Tracking
+        const IBT   = 0x1;
+
+        /// The file is compatible with the Shadow Stack
+        const SHSTK = 0x2;
+    }
+}
+
+bitflags! {
+    /// This structure contains the AArch64 features of the `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+    /// property.
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct AArch64Features: u32 {
+        /// The file is compatible with Branch Target Identification
+        const BTI = 0x1;
+
+        /// The file is compatible with Pointer Authentication
+        const PAC = 0x2;
+    }
+}
+
+/// This enum represents a single program property of a `NT_GNU_PROPERTY_TYPE_0` note. The
+/// processor-specific properties are decoded with the target machine of the ELF file.
+///
+/// ## See also
+/// - [Linux Extensions to gABI](https://gitlab.com/x86-psABIs/Linux-ABI) by H.J. Lu
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum GnuProperty<'a> {
+    /// `GNU_PROPERTY_STACK_SIZE` - The stack size needed by the program
+    StackSize(u64),
+
+    /// `GNU_PROPERTY_NO_COPY_ON_PROTECTED` - Copy relocations against protected symbols are not
+    /// allowed
+    NoCopyOnProtected,
+
+    /// `GNU_PROPERTY_X86_ISA_1_USED` - The x86 ISA levels used by the program
+    X86IsaUsed(X86IsaLevel),
+
+    /// `GNU_PROPERTY_X86_ISA_1_NEEDED` - The x86 ISA levels needed by the program
+    X86IsaNeeded(X86IsaLevel),
+
+    /// `GNU_PROPERTY_X86_FEATURE_1_AND` - The x86 features supported by the program
+    X86Features(X86Features),
+
+    /// `GNU_PROPERTY_AARCH64_FEATURE_1_AND` - The AArch64 features supported by the program
+    AArch64Features(AArch64Features),
+
+    /// This property has an unknown type (first field) with the data (second field)
+    Unknown(u32, &'a [u8]),
+}
+
+impl<'a> GnuProperty<'a> {
+    /// This function reads the property at the specified offset of the slice. The data of the
+    /// property is padded to 8 bytes in 64-bit ELF files and 4 bytes in 32-bit ELF files. After a
+    /// successful parsing, this function returns the property and the offset of the next property.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the property could be read completely
+    pub fn read(
+        ident: &ElfIdent, machine: TargetMachine, slice: &'a [u8], mut offset: usize,
+    ) -> Result<(Self, usize), Error> {
+        let alignment = match ident.class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => return Err(Error::InvalidClass),
+            ElfClass::Class32 => 4,
+            ElfClass::Class64 => 8,
+        };
+        let endian = &ident.endian;
+        let ty = endian.read::<u32>(slice, Some(&mut offset))?;
+        let size = endian.read::<u32>(slice, Some(&mut offset))? as usize;
+        let data = sub_slice(slice, offset, size, "GnuProperty")?;
+        let next_offset = (offset + size + alignment - 1) & !(alignment - 1);
+
+        let property = match (ty, machine) {
+            (0x1, _) => {
+                GnuProperty::StackSize(match size {
+                    4 => endian.read::<u32>(data, None)? as u64,
+                    _ => endian.read::<u64>(data, None)?,
+                })
+            }
+            (0x2, _) => GnuProperty::NoCopyOnProtected,
+            (0xC0000000, TargetMachine::ARM64) => {
+                GnuProperty::AArch64Features(AArch64Features::from_bits_retain(
+                    endian.read(data, None)?,
+                ))
+            }
+            (0xC0010002, TargetMachine::X86 | TargetMachine::X86_64) => {
+                GnuProperty::X86IsaUsed(X86IsaLevel::from_bits_retain(endian.read(data, None)?))
+            }
+            (0xC0008002, TargetMachine::X86 | TargetMachine::X86_64) => {
+                GnuProperty::X86IsaNeeded(X86IsaLevel::from_bits_retain(endian.read(data, None)?))
+            }
+            (0xC0000002, TargetMachine::X86 | TargetMachine::X86_64) => {
+                GnuProperty::X86Features(X86Features::from_bits_retain(endian.read(data, None)?))
+            }
+            (ty, _) => GnuProperty::Unknown(ty, data),
+        };
+        Ok((property, next_offset))
+    }
+
+    /// This function decodes all properties in the specified descriptor of a
+    /// `NT_GNU_PROPERTY_TYPE_0` note.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - A property ends outside of the descriptor
+    pub fn read_all(
+        ident: &ElfIdent, machine: TargetMachine, desc: &'a [u8],
+    ) -> Result<Vec<Self>, Error> {
+        let mut properties = Vec::new();
+        let mut offset = 0;
+        while offset < desc.len() {
+            let (property, next_offset) = Self::read(ident, machine, desc, offset)?;
+            properties.push(property);
+            offset = next_offset;
+        }
+        Ok(properties)
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns all program properties of the `NT_GNU_PROPERTY_TYPE_0` notes in the
+    /// ELF file. For more information, see [GnuProperty].
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::UnexpectedEnd] - A note or property ends outside of the note or descriptor
+    /// - For more errors while reading the notes, see [Elf::notes]
+    pub fn gnu_properties(&self) -> Result<Vec<GnuProperty<'a>>, Error> {
+        let mut properties = Vec::new();
+        for note in self.notes()? {
+            if let NoteKind::GnuProperty(desc) = note.kind()? {
+                properties.extend(GnuProperty::read_all(
+                    &self.header.ident,
+                    self.header.machine,
+                    desc,
+                )?);
+            }
+        }
+        Ok(properties)
+    }
+}
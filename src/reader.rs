@@ -0,0 +1,254 @@
+use crate::{
+    header::{
+        FileHeader,
+        ProgramHeader,
+        SectionHeader,
+        SectionType,
+    },
+    Elf,
+    Error,
+};
+use std::io::{
+    ErrorKind,
+    Read,
+    Seek,
+    SeekFrom,
+};
+
+/// This field contains the size of the largest file, program and section header, which is the size
+/// of the buffers for the headers
+const MAX_HEADER_SIZE: usize = 64;
+
+/// This structure represents a lazy reader over ELF data, which can't be held completely in the
+/// memory (like files in archives or remote storage). The file header is parsed when the reader is
+/// created, all other headers and data are read on demand with the [ProgramHeader::read] and
+/// [SectionHeader::read] logic. (Only available with `std`-feature)
+#[derive(Debug)]
+pub struct ElfReader<R> {
+    reader: R,
+    header: FileHeader,
+    program_header_count: usize,
+    section_header_count: usize,
+    string_table_index: usize,
+}
+
+impl<R: Read + Seek> ElfReader<R> {
+    /// This function creates a reader over the specified data. The file header is read and the
+    /// header tables are validated with the length of the data.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::IO] - The data can't be read or seeked
+    /// - [Error::InvalidMagic] - The data doesn't start with the magic bytes
+    /// - For errors while parsing the file header and validating the header tables, see
+    ///   [FileHeader::read] and [Elf::from_bytes]
+    pub fn new(mut reader: R) -> Result<Self, Error> {
+        let length = reader.seek(SeekFrom::End(0))?;
+        let mut bytes = [0; MAX_HEADER_SIZE];
+        let header_size = (MAX_HEADER_SIZE as u64).min(length) as usize;
+        Self::read_exact_at(&mut reader, 0, &mut bytes[..header_size], "FileHeader")?;
+        if !bytes.starts_with(&Elf::MAGIC_BYTES) {
+            return Err(Error::InvalidMagic);
+        }
+
+        // Read the file header and validate the header tables
+        let header = FileHeader::read(&bytes[..header_size], Elf::MAGIC_BYTES.len())?;
+        let length = usize::try_from(length).unwrap_or(usize::MAX);
+        let (program_header_count, section_header_count, string_table_index) =
+            Elf::header_table_layout(&header, length, |offset| {
+                let mut bytes = [0; MAX_HEADER_SIZE];
+                let bytes = bytes
+                    .get_mut(..(header.section_header_size as usize))
+                    .ok_or(Error::InvalidSectionHeaderSize(header.section_header_size))?;
+                Self::read_exact_at(&mut reader, offset as u64, bytes, "SectionHeader")?;
+                SectionHeader::read(&header.ident, bytes, 0)
+            })?;
+        Ok(Self {
+            reader,
+            header,
+            program_header_count,
+            section_header_count,
+            string_table_index,
+        })
+    }
+
+    /// This function seeks to the specified offset and fills the specified buffer. If the data ends
+    /// before the buffer is filled, the structure and offset are returned in the error.
+    fn read_exact_at(
+        reader: &mut R, offset: u64, buffer: &mut [u8], structure: &'static str,
+    ) -> Result<(), Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        reader.read_exact(buffer).map_err(|error| {
+            match error.kind() {
+                ErrorKind::UnexpectedEof => Error::UnexpectedEnd(structure, offset as usize),
+                _ => Error::IO(error),
+            }
+        })
+    }
+
+    /// This function returns the file header of the ELF data.
+    #[inline]
+    pub const fn file_header(&self) -> &FileHeader {
+        &self.header
+    }
+
+    /// This function returns the count of program headers (with extended numbering).
+    #[inline]
+    pub const fn program_header_count(&self) -> usize {
+        self.program_header_count
+    }
+
+    /// This function returns the count of section headers (with extended numbering).
+    #[inline]
+    pub const fn section_header_count(&self) -> usize {
+        self.section_header_count
+    }
+
+    /// This function returns the inner reader of this reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// This function reads the program header with the specified index in the program header
+    /// table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSegmentIndex] - The index is not in the program header table
+    /// - [Error::InvalidProgramHeaderSize] - The program header size is larger than the buffer
+    /// - [Error::IO] - The data can't be read or seeked
+    /// - For errors while parsing the program header, see [ProgramHeader::read]
+    pub fn program_header(&mut self, index: usize) -> Result<ProgramHeader, Error> {
+        if index >= self.program_header_count {
+            return Err(Error::InvalidSegmentIndex(index));
+        }
+
+        let size = self.header.program_header_size;
+        let offset = self.header.program_header_offset + (index * size as usize) as u64;
+        let mut bytes = [0; MAX_HEADER_SIZE];
+        let bytes = bytes
+            .get_mut(..(size as usize))
+            .ok_or(Error::InvalidProgramHeaderSize(size))?;
+        Self::read_exact_at(&mut self.reader, offset, bytes, "ProgramHeader")?;
+        ProgramHeader::read(&self.header.ident, bytes, 0)
+    }
+
+    /// This function reads the section header with the specified index in the section header
+    /// table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The index is not in the section header table
+    /// - [Error::InvalidSectionHeaderSize] - The section header size is larger than the buffer
+    /// - [Error::IO] - The data can't be read or seeked
+    /// - For errors while parsing the section header, see [SectionHeader::read]
+    pub fn section_header(&mut self, index: usize) -> Result<SectionHeader, Error> {
+        if index >= self.section_header_count {
+            return Err(Error::InvalidSectionIndex(index));
+        }
+
+        let size = self.header.section_header_size;
+        let offset = self.header.section_header_offset + (index * size as usize) as u64;
+        let mut bytes = [0; MAX_HEADER_SIZE];
+        let bytes = bytes
+            .get_mut(..(size as usize))
+            .ok_or(Error::InvalidSectionHeaderSize(size))?;
+        Self::read_exact_at(&mut self.reader, offset, bytes, "SectionHeader")?;
+        SectionHeader::read(&self.header.ident, bytes, 0)
+    }
+
+    /// This function reads all program headers of the program header table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading a program header, see [ElfReader::program_header]
+    pub fn program_headers(&mut self) -> Result<Vec<ProgramHeader>, Error> {
+        (0..self.program_header_count)
+            .map(|index| self.program_header(index))
+            .collect()
+    }
+
+    /// This function reads all section headers of the section header table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading a section header, see [ElfReader::section_header]
+    pub fn section_headers(&mut self) -> Result<Vec<SectionHeader>, Error> {
+        (0..self.section_header_count)
+            .map(|index| self.section_header(index))
+            .collect()
+    }
+
+    /// This function reads the name of the specified section from the section header string
+    /// table. The name is read in small chunks until the null terminator.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The string table index is not in the section header table
+    /// - [Error::UnexpectedEnd] - The name isn't terminated inside of the string table
+    /// - [Error::InvalidString] - The name is not valid UTF-8
+    /// - [Error::IO] - The data can't be read or seeked
+    pub fn section_name(&mut self, section_header: &SectionHeader) -> Result<String, Error> {
+        let string_table = self.section_header(self.string_table_index)?;
+        let index = section_header.name as usize;
+        let mut name = Vec::new();
+        let mut chunk = [0; MAX_HEADER_SIZE];
+        loop {
+            let offset = index as u64 + name.len() as u64;
+            let length =
+                self.read_data(string_table.offset, string_table.size, offset, &mut chunk)?;
+            if length == 0 {
+                return Err(Error::UnexpectedEnd("String", index));
+            }
+            match chunk[..length].iter().position(|byte| *byte == 0) {
+                Some(end) => {
+                    name.extend(&chunk[..end]);
+                    break;
+                }
+                None => name.extend(&chunk[..length]),
+            }
+        }
+        String::from_utf8(name).map_err(|_| Error::InvalidString(index))
+    }
+
+    /// This function reads the data of the specified section at the specified offset inside of the
+    /// section into the specified buffer. The data is read until the buffer is full or the
+    /// section ends, and the count of the read bytes is returned. Sections without data in the
+    /// file (like `.bss`) have no bytes to read.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The data ends before the section data
+    /// - [Error::IO] - The data can't be read or seeked
+    pub fn read_section_data(
+        &mut self, section_header: &SectionHeader, offset: u64, buffer: &mut [u8],
+    ) -> Result<usize, Error> {
+        if section_header.ty == SectionType::NoBits {
+            return Ok(0);
+        }
+        self.read_data(section_header.offset, section_header.size, offset, buffer)
+    }
+
+    /// This function reads the data of the specified segment at the specified offset inside of the
+    /// segment into the specified buffer. The data is read until the buffer is full or the file
+    /// data of the segment ends, and the count of the read bytes is returned.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The data ends before the segment data
+    /// - [Error::IO] - The data can't be read or seeked
+    pub fn read_segment_data(
+        &mut self, program_header: &ProgramHeader, offset: u64, buffer: &mut [u8],
+    ) -> Result<usize, Error> {
+        self.read_data(
+            program_header.offset,
+            program_header.file_size,
+            offset,
+            buffer,
+        )
+    }
+
+    /// This function reads the data of a section or segment with the specified file offset and
+    /// size at the specified offset into the buffer.
+    fn read_data(
+        &mut self, start: u64, size: u64, offset: u64, buffer: &mut [u8],
+    ) -> Result<usize, Error> {
+        let length = (buffer.len() as u64).min(size.saturating_sub(offset)) as usize;
+        let offset = start.saturating_add(offset);
+        Self::read_exact_at(&mut self.reader, offset, &mut buffer[..length], "Data")?;
+        Ok(length)
+    }
+}
@@ -0,0 +1,76 @@
+relocation_types! {
+    /// This enum represents the relocation types of the ARM/AArch32 architecture.
+    ///
+    /// ## See also
+    /// - [ELF for the Arm Architecture](https://github.com/ARM-software/abi-aa/blob/main/aaelf32/aaelf32.rst)
+    ///   by Arm Limited
+    ArmRelocationType {
+        R_ARM_NONE              = 0,
+        R_ARM_PC24              = 1,
+        R_ARM_ABS32             = 2,
+        R_ARM_REL32             = 3,
+        R_ARM_LDR_PC_G0         = 4,
+        R_ARM_ABS16             = 5,
+        R_ARM_ABS12             = 6,
+        R_ARM_THM_ABS5          = 7,
+        R_ARM_ABS8              = 8,
+        R_ARM_SBREL32           = 9,
+        R_ARM_THM_CALL          = 10,
+        R_ARM_THM_PC8           = 11,
+        R_ARM_BREL_ADJ          = 12,
+        R_ARM_TLS_DESC          = 13,
+        R_ARM_THM_SWI8          = 14,
+        R_ARM_XPC25             = 15,
+        R_ARM_THM_XPC22         = 16,
+        R_ARM_TLS_DTPMOD32      = 17,
+        R_ARM_TLS_DTPOFF32      = 18,
+        R_ARM_TLS_TPOFF32       = 19,
+        R_ARM_COPY              = 20,
+        R_ARM_GLOB_DAT          = 21,
+        R_ARM_JUMP_SLOT         = 22,
+        R_ARM_RELATIVE          = 23,
+        R_ARM_GOTOFF32          = 24,
+        R_ARM_BASE_PREL         = 25,
+        R_ARM_GOT_BREL          = 26,
+        R_ARM_PLT32             = 27,
+        R_ARM_CALL              = 28,
+        R_ARM_JUMP24            = 29,
+        R_ARM_THM_JUMP24        = 30,
+        R_ARM_BASE_ABS          = 31,
+        R_ARM_TARGET1           = 38,
+        R_ARM_SBREL31           = 39,
+        R_ARM_V4BX              = 40,
+        R_ARM_TARGET2           = 41,
+        R_ARM_PREL31            = 42,
+        R_ARM_MOVW_ABS_NC       = 43,
+        R_ARM_MOVT_ABS          = 44,
+        R_ARM_MOVW_PREL_NC      = 45,
+        R_ARM_MOVT_PREL         = 46,
+        R_ARM_THM_MOVW_ABS_NC   = 47,
+        R_ARM_THM_MOVT_ABS      = 48,
+        R_ARM_THM_MOVW_PREL_NC  = 49,
+        R_ARM_THM_MOVT_PREL     = 50,
+        R_ARM_THM_JUMP19        = 51,
+        R_ARM_THM_JUMP6         = 52,
+        R_ARM_THM_ALU_PREL_11_0 = 53,
+        R_ARM_THM_PC12          = 54,
+        R_ARM_ABS32_NOI         = 55,
+        R_ARM_REL32_NOI         = 56,
+        R_ARM_GOT_ABS           = 95,
+        R_ARM_GOT_PREL          = 96,
+        R_ARM_GOT_BREL12        = 97,
+        R_ARM_GOTOFF12          = 98,
+        R_ARM_GOTRELAX          = 99,
+        R_ARM_THM_JUMP11        = 102,
+        R_ARM_THM_JUMP8         = 103,
+        R_ARM_TLS_GD32          = 104,
+        R_ARM_TLS_LDM32         = 105,
+        R_ARM_TLS_LDO32         = 106,
+        R_ARM_TLS_IE32          = 107,
+        R_ARM_TLS_LE32          = 108,
+        R_ARM_TLS_LDO12         = 109,
+        R_ARM_TLS_LE12          = 110,
+        R_ARM_TLS_IE12GP        = 111,
+        R_ARM_IRELATIVE         = 160,
+    }
+}
@@ -0,0 +1,352 @@
+use crate::{
+    endian::sub_slice,
+    header::{
+        ident::{
+            ElfClass,
+            ElfIdent,
+        },
+        read_class_dependent,
+        SectionHeader,
+        SectionType,
+        TargetMachine,
+    },
+    std::fmt,
+    symbol::SymbolTable,
+    Elf,
+    Error,
+};
+
+/// This macro generates an enum with the specified relocation types of an architecture. The names
+/// of the variants are equal to the names in the architecture's ABI, so the [fmt::Display]
+/// implementation prints the name of the relocation type. Unknown types are mapped to the
+/// `Unknown` variant.
+macro_rules! relocation_types {
+    (
+        $(#[$meta: meta])*
+        $name: ident {
+            $($variant: ident = $value: literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u32)]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+        pub enum $name {
+            $($variant = $value,)*
+            Unknown(u32) = 0xFFFFFFFF,
+        }
+
+        impl From<u32> for $name {
+            fn from(value: u32) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    value => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl $crate::std::fmt::Display for $name {
+            fn fmt(&self, formatter: &mut $crate::std::fmt::Formatter<'_>) -> $crate::std::fmt::Result {
+                match self {
+                    $(Self::$variant => formatter.write_str(stringify!($variant)),)*
+                    Self::Unknown(value) => write!(formatter, "Unknown({})", value),
+                }
+            }
+        }
+    };
+}
+
+pub mod aarch64;
+pub mod arm;
+pub mod riscv;
+pub mod x86_64;
+
+/// This enum represents the architecture-specific type of a relocation. The type is determined by
+/// the target machine of the ELF file and the raw type of the relocation. The [fmt::Display]
+/// implementation prints the name of the relocation type like `R_X86_64_PC32`.
+///
+/// - [RelocationKind::X86_64]: Relocation type of x86_64
+/// - [RelocationKind::ARM]: Relocation type of ARM/AArch32
+/// - [RelocationKind::ARM64]: Relocation type of ARM64/AArch64
+/// - [RelocationKind::RISCV]: Relocation type of RISC-V
+/// - [RelocationKind::Unknown]: Relocation type of an unsupported target machine
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub enum RelocationKind {
+    X86_64(x86_64::X86_64RelocationType),
+    ARM(arm::ArmRelocationType),
+    ARM64(aarch64::AArch64RelocationType),
+    RISCV(riscv::RiscVRelocationType),
+    Unknown(u32),
+}
+
+impl RelocationKind {
+    /// This function maps the specified raw relocation type to the relocation type of the
+    /// specified target machine.
+    pub fn new(machine: TargetMachine, ty: u32) -> Self {
+        match machine {
+            TargetMachine::X86_64 => Self::X86_64(ty.into()),
+            TargetMachine::ARM => Self::ARM(ty.into()),
+            TargetMachine::ARM64 => Self::ARM64(ty.into()),
+            TargetMachine::RISCV => Self::RISCV(ty.into()),
+            TargetMachine::X86 | TargetMachine::None => Self::Unknown(ty),
+        }
+    }
+}
+
+impl fmt::Display for RelocationKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::X86_64(ty) => ty.fmt(formatter),
+            Self::ARM(ty) => ty.fmt(formatter),
+            Self::ARM64(ty) => ty.fmt(formatter),
+            Self::RISCV(ty) => ty.fmt(formatter),
+            Self::Unknown(value) => write!(formatter, "Unknown({})", value),
+        }
+    }
+}
+
+/// This structure represents a single relocation entry of a `SHT_REL` or `SHT_RELA` section in an
+/// ELF file. The entry contains the location to be relocated, the index of the referenced symbol,
+/// the processor-specific type of the relocation and the explicit addend of `SHT_RELA` entries.
+///
+/// ## See also
+/// - [Relocation](https://www.sco.com/developers/gabi/latest/ch4.reloc.html) by SCO, Inc.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub struct Relocation {
+    /// This field indicates the location, at which the relocation should be applied. For
+    /// relocatable files this is the offset in the target section, for executables and shared
+    /// objects this is the virtual address.
+    pub offset: u64,
+
+    /// This field indicates the index of the referenced symbol in the symbol table of the
+    /// relocation section.
+    pub symbol: u32,
+
+    /// This field indicates the processor-specific type of the relocation.
+    pub ty: u32,
+
+    /// This field indicates the explicit addend of the relocation. This value is none for entries
+    /// of `SHT_REL` sections, whose addend is stored in the relocated location.
+    pub addend: Option<i64>,
+}
+
+impl Relocation {
+    /// This function returns the size of a single relocation entry for ELF files with the specified
+    /// class. The entry is larger, if it contains an explicit addend.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass, with_addend: bool) -> Result<usize, Error> {
+        match (class, with_addend) {
+            (ElfClass::Invalid | ElfClass::Unknown(_), _) => Err(Error::InvalidClass),
+            (ElfClass::Class32, false) => Ok(8),
+            (ElfClass::Class32, true) => Ok(12),
+            (ElfClass::Class64, false) => Ok(16),
+            (ElfClass::Class64, true) => Ok(24),
+        }
+    }
+
+    /// This function reads the data from the slice (with offset) and parses it into a [Relocation]
+    /// structure for the ELF file. The addend is only read, if it's specified that the entry has an
+    /// explicit addend.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the relocation could be read completely
+    ///
+    /// ## See also
+    /// - [Relocation](https://www.sco.com/developers/gabi/latest/ch4.reloc.html) by SCO, Inc.
+    pub fn read(
+        ident: &ElfIdent, slice: &[u8], mut offset: usize, with_addend: bool,
+    ) -> Result<Self, Error> {
+        sub_slice(
+            slice,
+            offset,
+            Self::size(ident.class, with_addend)?,
+            "Relocation",
+        )?;
+        let relocation_offset = read_class_dependent!(ident, slice, &mut offset);
+        let info = read_class_dependent!(ident, slice, &mut offset);
+        let addend = if with_addend {
+            Some(match ident.class {
+                ElfClass::Class32 => ident.endian.read::<i32>(slice, Some(&mut offset))? as i64,
+                _ => ident.endian.read::<i64>(slice, Some(&mut offset))?,
+            })
+        } else {
+            None
+        };
+
+        // The split of the info field into symbol and type differs between the classes
+        let (symbol, ty) = match ident.class {
+            ElfClass::Class32 => ((info >> 8) as u32, (info & 0xFF) as u32),
+            _ => ((info >> 32) as u32, (info & 0xFFFFFFFF) as u32),
+        };
+
+        Ok(Self {
+            offset: relocation_offset,
+            symbol,
+            ty,
+            addend,
+        })
+    }
+
+    /// This function returns the architecture-specific type of this relocation for the specified
+    /// target machine. For more information, see [RelocationKind].
+    #[inline]
+    pub fn kind(&self, machine: TargetMachine) -> RelocationKind {
+        RelocationKind::new(machine, self.ty)
+    }
+}
+
+/// This structure represents a view over a relocation table of an ELF file. The table is either a
+/// `SHT_REL` section with implicit addends or a `SHT_RELA` section with explicit addends.
+///
+/// ## See also
+/// - [Relocation](https://www.sco.com/developers/gabi/latest/ch4.reloc.html) by SCO, Inc.
+#[derive(Clone, Copy, Debug)]
+pub struct RelocationTable<'a> {
+    ident: ElfIdent,
+    bytes: &'a [u8],
+    entry_size: usize,
+    with_addend: bool,
+    section_header: Option<SectionHeader>,
+}
+
+impl<'a> RelocationTable<'a> {
+    /// This function creates a relocation table view over the specified bytes of the table. If the
+    /// entry size is zero, the size of a relocation for the class of the ELF file is used.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub fn new(
+        ident: ElfIdent, bytes: &'a [u8], entry_size: usize, with_addend: bool,
+    ) -> Result<Self, Error> {
+        let entry_size = entry_size.max(Relocation::size(ident.class, with_addend)?);
+        Ok(Self {
+            ident,
+            bytes,
+            entry_size,
+            with_addend,
+            section_header: None,
+        })
+    }
+
+    /// This function creates a relocation table view over the data of the specified `SHT_REL` or
+    /// `SHT_RELA` section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    pub fn from_section(elf: &Elf<'a>, section_header: &SectionHeader) -> Result<Self, Error> {
+        let mut table = Self::new(
+            elf.header.ident,
+            section_header.data(elf)?,
+            section_header.entry_size as usize,
+            section_header.ty == SectionType::Rela,
+        )?;
+        table.section_header = Some(*section_header);
+        Ok(table)
+    }
+
+    /// This function returns the count of relocations in the relocation table.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / self.entry_size
+    }
+
+    /// This function returns whether the relocation table has no relocations.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// This function returns whether the relocations of this table have explicit addends.
+    #[inline]
+    pub const fn with_addend(&self) -> bool {
+        self.with_addend
+    }
+
+    /// This function returns the header of the section, which contains this relocation table. This
+    /// is none if the table wasn't read from a section.
+    #[inline]
+    pub const fn section_header(&self) -> Option<&SectionHeader> {
+        self.section_header.as_ref()
+    }
+
+    /// This function reads the relocation at the specified index in the relocation table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the relocation table
+    pub fn get(&self, index: usize) -> Result<Relocation, Error> {
+        Relocation::read(
+            &self.ident,
+            self.bytes,
+            index.saturating_mul(self.entry_size),
+            self.with_addend,
+        )
+    }
+
+    /// This function returns an iterator over all relocations in the relocation table.
+    pub fn iter(&self) -> impl Iterator<Item = Result<Relocation, Error>> + 'a {
+        let table = *self;
+        (0..self.len()).map(move |index| table.get(index))
+    }
+
+    /// This function returns the header of the section, to which the relocations are applied. The
+    /// index of this section is specified in the `info` field of the relocation section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The table has no target section or the index is not in the
+    ///   section header table
+    pub fn target_section<'b>(&self, elf: &'b Elf) -> Result<&'b SectionHeader, Error> {
+        match self
+            .section_header
+            .map(|section_header| section_header.info)
+        {
+            None | Some(0) => Err(Error::InvalidSectionIndex(0)),
+            Some(index) => elf.section_header(index as usize),
+        }
+    }
+
+    /// This function returns the symbol table, which is referenced by the relocations. The index of
+    /// this section is specified in the `link` field of the relocation section. If the table wasn't
+    /// read from a section, the relocations reference the dynamic symbol table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The table has no symbol table or the index is not in the
+    ///   section header table
+    /// - [Error::NotEnoughBytes] - The data of the symbol table is not in the ELF data
+    /// - For more errors of tables without section, see [Elf::dynamic_symbol_table]
+    pub fn symbol_table(&self, elf: &Elf<'a>) -> Result<SymbolTable<'a>, Error> {
+        match self
+            .section_header
+            .map(|section_header| section_header.link)
+        {
+            None => elf.dynamic_symbol_table(),
+            Some(0) => Err(Error::InvalidSectionIndex(0)),
+            Some(index) => SymbolTable::from_section(elf, elf.section_header(index as usize)?),
+        }
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns an iterator over all `SHT_REL` and `SHT_RELA` sections with their
+    /// names as relocation tables.
+    pub fn relocation_tables(
+        &self,
+    ) -> impl Iterator<Item = Result<(&'a str, RelocationTable<'a>), Error>> + '_ {
+        self.section_headers
+            .iter()
+            .flatten()
+            .filter(|section_header| {
+                matches!(section_header.ty, SectionType::Rel | SectionType::Rela)
+            })
+            .map(|section_header| {
+                Ok((
+                    self.section_name(section_header)?,
+                    RelocationTable::from_section(self, section_header)?,
+                ))
+            })
+    }
+}
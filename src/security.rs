@@ -0,0 +1,158 @@
+use crate::{
+    dynamic::{
+        DynamicEntry,
+        DynamicFlags,
+        DynamicFlags1,
+    },
+    header::{
+        FileType,
+        SegmentFlags,
+        SegmentType,
+    },
+    Elf,
+    Error,
+};
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+/// This enum represents the relocation read-only (RELRO) protection of an ELF file. With partial
+/// RELRO, the `PT_GNU_RELRO` segment is made read-only after the relocation. With full RELRO, all
+/// symbols are bound at the start of the program, so the whole GOT is made read-only.
+///
+/// - [Relro::None]: No `PT_GNU_RELRO` segment
+/// - [Relro::Partial]: `PT_GNU_RELRO` segment with lazy binding
+/// - [Relro::Full]: `PT_GNU_RELRO` segment with immediate binding (`BIND_NOW`)
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub enum Relro {
+    #[default]
+    None,
+    Partial,
+    Full,
+}
+
+/// This structure represents the hardening features of an ELF file like reported by `checksec`.
+/// The report is created with [Elf::security_report].
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub struct SecurityReport<'a> {
+    /// This field indicates whether the stack is not executable. The stack is executable, when
+    /// the ELF file has no `PT_GNU_STACK` segment or the segment has the executable flag.
+    pub nx_stack: bool,
+
+    /// This field indicates the relocation read-only protection of the ELF file.
+    pub relro: Relro,
+
+    /// This field indicates whether the ELF file is a position-independent executable. This is
+    /// detected by a shared object with the `DF_1_PIE` flag or a `DT_DEBUG` entry.
+    pub pie: bool,
+
+    /// This field indicates whether the ELF file is protected by stack canaries. This is detected
+    /// by the `__stack_chk_fail` symbol.
+    pub stack_canary: bool,
+
+    /// This field contains the sorted names of the fortified functions (like `__memcpy_chk`),
+    /// which are used by the ELF file.
+    pub fortified_functions: Vec<&'a str>,
+
+    /// This field contains the deprecated library search path (`DT_RPATH`) of the ELF file.
+    pub rpath: Option<&'a str>,
+
+    /// This field contains the library search path (`DT_RUNPATH`) of the ELF file.
+    pub runpath: Option<&'a str>,
+
+    /// This field contains the indices of all segments in the program header table, which are
+    /// writable and executable at the same time.
+    pub writable_executable_segments: Vec<usize>,
+}
+
+impl<'a> Elf<'a> {
+    /// This function creates a report over the hardening features of the ELF file. ELF files
+    /// without dynamic section, symbol table or dynamic symbol table are supported.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of a section or segment is not in the ELF data
+    /// - [Error::UnexpectedEnd] - A dynamic entry or symbol ends outside of the table
+    /// - For more errors while reading the symbols and dynamic section, see [Elf::symbol_table],
+    ///   [Elf::dynamic_symbol_table] and [Elf::dynamic]
+    pub fn security_report(&self) -> Result<SecurityReport<'a>, Error> {
+        let program_headers = self.program_headers.iter().flatten();
+        let has_segment = |ty: SegmentType| {
+            program_headers
+                .clone()
+                .any(|program_header| program_header.ty == ty)
+        };
+
+        // Read the binding and debug entries of the dynamic section, if the section exists
+        let mut bind_now = false;
+        let mut pie = false;
+        let (rpath, runpath) = match self.dynamic() {
+            Ok(entries) => {
+                for entry in entries {
+                    match entry? {
+                        DynamicEntry::BindNow => bind_now = true,
+                        DynamicEntry::Flags(flags) => {
+                            bind_now |= flags.contains(DynamicFlags::BIND_NOW)
+                        }
+                        DynamicEntry::Flags1(flags) => {
+                            bind_now |= flags.contains(DynamicFlags1::NOW);
+                            pie |= flags.contains(DynamicFlags1::PIE);
+                        }
+                        DynamicEntry::Debug(_) => pie = true,
+                        _ => {}
+                    }
+                }
+                (self.rpath()?, self.runpath()?)
+            }
+            Err(Error::MissingSection(_)) => (None, None),
+            Err(error) => return Err(error),
+        };
+
+        // Search the canary and fortified functions in both symbol tables
+        let mut stack_canary = false;
+        let mut fortified_functions = Vec::new();
+        for symbol_table in [self.symbol_table(), self.dynamic_symbol_table()] {
+            let symbol_table = match symbol_table {
+                Ok(symbol_table) => symbol_table,
+                Err(Error::MissingSection(_) | Error::MissingDynamicEntry(_)) => continue,
+                Err(error) => return Err(error),
+            };
+            for symbol in symbol_table.iter() {
+                // Remove the version of the symbol name (like `__memcpy_chk@GLIBC_2.3.4`)
+                let (name, _) = symbol?;
+                let name = name.split('@').next().unwrap_or(name);
+                if name == "__stack_chk_fail" {
+                    stack_canary = true;
+                } else if name.starts_with("__") && name.ends_with("_chk") && name.len() > 6 {
+                    fortified_functions.push(name);
+                }
+            }
+        }
+        fortified_functions.sort_unstable();
+        fortified_functions.dedup();
+
+        Ok(SecurityReport {
+            nx_stack: program_headers.clone().any(|program_header| {
+                program_header.ty == SegmentType::GNUStack
+                    && !program_header.flags.contains(SegmentFlags::EXECUTABLE)
+            }),
+            relro: match (has_segment(SegmentType::GNURelro), bind_now) {
+                (false, _) => Relro::None,
+                (true, false) => Relro::Partial,
+                (true, true) => Relro::Full,
+            },
+            pie: self.header.ty == FileType::SharedObject && pie,
+            stack_canary,
+            fortified_functions,
+            rpath,
+            runpath,
+            writable_executable_segments: program_headers
+                .enumerate()
+                .filter(|(_, program_header)| {
+                    program_header
+                        .flags
+                        .contains(SegmentFlags::WRITABLE | SegmentFlags::EXECUTABLE)
+                })
+                .map(|(index, _)| index)
+                .collect(),
+        })
+    }
+}
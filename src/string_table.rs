@@ -0,0 +1,47 @@
+use crate::{
+    std::str,
+    Error,
+};
+
+/// This structure represents a view over a string table of an ELF file. The string table holds
+/// null-terminated strings, which are referenced by their byte index in the table. The view borrows
+/// the data from the ELF data, so no string is copied.
+///
+/// ## See also
+/// - [String Table](https://www.sco.com/developers/gabi/latest/ch4.strtab.html) by SCO, Inc.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub struct StringTable<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> StringTable<'a> {
+    /// This function creates a string table view over the specified bytes of the string table.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// This function returns the null-terminated string, which starts at the specified index in the
+    /// string table. The returned string doesn't contain the null byte.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The string starts or ends outside of the string table
+    /// - [Error::InvalidString] - The string is not valid UTF-8
+    pub fn get(&self, index: usize) -> Result<&'a str, Error> {
+        let bytes = self
+            .bytes
+            .get(index..)
+            .ok_or(Error::UnexpectedEnd("string", index))?;
+        let length = bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or(Error::UnexpectedEnd("string", index))?;
+        str::from_utf8(&bytes[..length]).map_err(|_| Error::InvalidString(index))
+    }
+
+    /// This function returns the raw bytes of the string table.
+    #[inline]
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
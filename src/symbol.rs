@@ -0,0 +1,562 @@
+use crate::{
+    endian::sub_slice,
+    header::{
+        ident::{
+            ElfClass,
+            ElfIdent,
+        },
+        read_class_dependent,
+        SectionHeader,
+        SectionType,
+    },
+    std::ptr,
+    string_table::StringTable,
+    Elf,
+    Error,
+};
+
+#[cfg(not(feature = "std"))] use alloc::vec::Vec;
+
+/// This enum represents the binding of a symbol. The binding determines the linkage visibility and
+/// behavior of the symbol.
+///
+/// - [SymbolBinding::Local]: Symbol is not visible outside the object file
+/// - [SymbolBinding::Global]: Symbol is visible to all object files being combined
+/// - [SymbolBinding::Weak]: Symbol is global, but with lower precedence
+/// - [SymbolBinding::GnuUnique]: Symbol is unique in the whole process (GNU extension)
+///
+/// ## See also
+/// - [Symbol Table](https://www.sco.com/developers/gabi/latest/ch4.symtab.html) by SCO, Inc.
+#[repr(u8)]
+#[rustfmt::skip]
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub enum SymbolBinding {
+    #[default]
+    Local       = 0,
+    Global      = 1,
+    Weak        = 2,
+    GnuUnique   = 10,
+    Unknown(u8) = 0xFF
+}
+
+impl From<u8> for SymbolBinding {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Local,
+            1 => Self::Global,
+            2 => Self::Weak,
+            10 => Self::GnuUnique,
+            value => Self::Unknown(value),
+        }
+    }
+}
+
+/// This enum represents the type of a symbol. The type provides a general classification for the
+/// associated entity.
+///
+/// - [SymbolType::NoType]: The symbol's type is not specified
+/// - [SymbolType::Object]: The symbol is associated with a data object like a variable
+/// - [SymbolType::Function]: The symbol is associated with a function or other executable code
+/// - [SymbolType::Section]: The symbol is associated with a section
+/// - [SymbolType::File]: The symbol's name gives the name of the source file
+/// - [SymbolType::Common]: The symbol labels an uninitialized common block
+/// - [SymbolType::TLS]: The symbol specifies a thread-local storage entity
+/// - [SymbolType::GnuIndirectFunction]: The symbol is an indirect function (GNU extension)
+///
+/// ## See also
+/// - [Symbol Table](https://www.sco.com/developers/gabi/latest/ch4.symtab.html) by SCO, Inc.
+#[repr(u8)]
+#[rustfmt::skip]
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub enum SymbolType {
+    #[default]
+    NoType              = 0,
+    Object              = 1,
+    Function            = 2,
+    Section             = 3,
+    File                = 4,
+    Common              = 5,
+    TLS                 = 6,
+    GnuIndirectFunction = 10,
+    Unknown(u8)         = 0xFF
+}
+
+impl From<u8> for SymbolType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoType,
+            1 => Self::Object,
+            2 => Self::Function,
+            3 => Self::Section,
+            4 => Self::File,
+            5 => Self::Common,
+            6 => Self::TLS,
+            10 => Self::GnuIndirectFunction,
+            value => Self::Unknown(value),
+        }
+    }
+}
+
+/// This enum represents the visibility of a symbol. The visibility specifies how the symbol may be
+/// accessed once it has become part of an executable or shared object.
+///
+/// - [SymbolVisibility::Default]: The visibility is specified by the binding of the symbol
+/// - [SymbolVisibility::Internal]: Processor-specific hidden class
+/// - [SymbolVisibility::Hidden]: The symbol is not visible to other components
+/// - [SymbolVisibility::Protected]: The symbol is visible but can't be preempted
+///
+/// ## See also
+/// - [Symbol Table](https://www.sco.com/developers/gabi/latest/ch4.symtab.html) by SCO, Inc.
+#[repr(u8)]
+#[rustfmt::skip]
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub enum SymbolVisibility {
+    #[default]
+    Default   = 0,
+    Internal  = 1,
+    Hidden    = 2,
+    Protected = 3
+}
+
+impl From<u8> for SymbolVisibility {
+    fn from(value: u8) -> Self {
+        match value & 0x3 {
+            0 => Self::Default,
+            1 => Self::Internal,
+            2 => Self::Hidden,
+            _ => Self::Protected,
+        }
+    }
+}
+
+/// This structure represents a single entry of a symbol table in an ELF file. The entry contains
+/// the name, value, size and classification of the symbol and the index of the section, in which
+/// the symbol is defined.
+///
+/// ## See also
+/// - [Symbol Table](https://www.sco.com/developers/gabi/latest/ch4.symtab.html) by SCO, Inc.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+pub struct Symbol {
+    /// This field indicates the index of the name in the string table linked by the symbol table.
+    pub name: u32,
+
+    /// This field indicates the value of the symbol. Depending on the context this can be an
+    /// absolute value or an address.
+    pub value: u64,
+
+    /// This field indicates the size of the entity associated with this symbol. This value is zero
+    /// if the symbol has no or an unknown size.
+    pub size: u64,
+
+    /// This field indicates the binding of the symbol. For more information, see [SymbolBinding].
+    pub binding: SymbolBinding,
+
+    /// This field indicates the type of the symbol. For more information, see [SymbolType].
+    pub ty: SymbolType,
+
+    /// This field indicates the visibility of the symbol. For more information, see
+    /// [SymbolVisibility].
+    pub visibility: SymbolVisibility,
+
+    /// This field indicates the index of the section, in which the symbol is defined. This value is
+    /// [Symbol::UNDEFINED_SECTION] if the symbol is undefined.
+    pub section_index: u16,
+}
+
+impl Symbol {
+    /// This field contains the section index of undefined symbols
+    pub const UNDEFINED_SECTION: u16 = 0x0000;
+
+    /// This field contains the section index of symbols with absolute values
+    pub const ABSOLUTE_SECTION: u16 = 0xFFF1;
+
+    /// This field contains the section index of common symbols
+    pub const COMMON_SECTION: u16 = 0xFFF2;
+
+    /// This field contains the section index (`SHN_XINDEX`) of symbols, which store the real
+    /// section index in the `SHT_SYMTAB_SHNDX` section
+    pub const EXTENDED_SECTION: u16 = 0xFFFF;
+
+    /// This function returns the size of a single symbol for ELF files with the specified class.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub const fn size(class: ElfClass) -> Result<usize, Error> {
+        match class {
+            ElfClass::Invalid | ElfClass::Unknown(_) => Err(Error::InvalidClass),
+            ElfClass::Class32 => Ok(16),
+            ElfClass::Class64 => Ok(24),
+        }
+    }
+
+    /// This function reads the data from the slice (with offset) and parses it into a [Symbol]
+    /// structure for the ELF file.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidEndian] - The provided ELF file's endianness is not valid
+    /// - [Error::UnexpectedEnd] - The slice ends before the symbol could be read completely
+    ///
+    /// ## See also
+    /// - [Symbol Table](https://www.sco.com/developers/gabi/latest/ch4.symtab.html) by SCO, Inc.
+    pub fn read(ident: &ElfIdent, slice: &[u8], mut offset: usize) -> Result<Self, Error> {
+        sub_slice(slice, offset, Self::size(ident.class)?, "Symbol")?;
+        let endian = &ident.endian;
+        let name = endian.read::<u32>(slice, Some(&mut offset))?;
+
+        // The 32-bit symbol places value and size before the info fields
+        let (mut value, mut size) = (0, 0);
+        if ident.class == ElfClass::Class32 {
+            value = read_class_dependent!(ident, slice, &mut offset);
+            size = read_class_dependent!(ident, slice, &mut offset);
+        }
+
+        // Read info, other and section index fields
+        let info = endian.read::<u8>(slice, Some(&mut offset))?;
+        let other = endian.read::<u8>(slice, Some(&mut offset))?;
+        let section_index = endian.read::<u16>(slice, Some(&mut offset))?;
+
+        // The 64-bit symbol places value and size after the info fields
+        if ident.class == ElfClass::Class64 {
+            value = read_class_dependent!(ident, slice, &mut offset);
+            size = read_class_dependent!(ident, slice, &mut offset);
+        }
+
+        Ok(Self {
+            name,
+            value,
+            size,
+            binding: SymbolBinding::from(info >> 4),
+            ty: SymbolType::from(info & 0xF),
+            visibility: SymbolVisibility::from(other),
+            section_index,
+        })
+    }
+
+    /// This function returns whether the symbol is undefined in this file, so it must be resolved
+    /// by another object.
+    #[inline]
+    pub const fn is_undefined(&self) -> bool {
+        self.section_index == Self::UNDEFINED_SECTION
+    }
+}
+
+/// This structure represents a view over a symbol table of an ELF file. The symbol table contains
+/// the symbols and resolves their names with the linked string table.
+///
+/// ## See also
+/// - [Symbol Table](https://www.sco.com/developers/gabi/latest/ch4.symtab.html) by SCO, Inc.
+#[derive(Clone, Copy, Debug)]
+pub struct SymbolTable<'a> {
+    ident: ElfIdent,
+    bytes: &'a [u8],
+    entry_size: usize,
+    strings: StringTable<'a>,
+    extended_indices: &'a [u8],
+}
+
+impl<'a> SymbolTable<'a> {
+    /// This function creates a symbol table view over the specified bytes of the table. If the
+    /// entry size is zero, the size of a symbol for the class of the ELF file is used.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    pub fn new(
+        ident: ElfIdent, bytes: &'a [u8], entry_size: usize, strings: StringTable<'a>,
+    ) -> Result<Self, Error> {
+        let entry_size = match entry_size {
+            0 => Symbol::size(ident.class)?,
+            entry_size => entry_size.max(Symbol::size(ident.class)?),
+        };
+        Ok(Self {
+            ident,
+            bytes,
+            entry_size,
+            strings,
+            extended_indices: &[],
+        })
+    }
+
+    /// This function sets the data of the `SHT_SYMTAB_SHNDX` section, which contains the section
+    /// indices of the symbols with the [Symbol::EXTENDED_SECTION] index.
+    #[inline]
+    pub const fn with_extended_indices(mut self, extended_indices: &'a [u8]) -> Self {
+        self.extended_indices = extended_indices;
+        self
+    }
+
+    /// This function creates a symbol table view over the data of the specified section. The names
+    /// of the symbols are resolved with the string table linked by the section header. If there is
+    /// a `SHT_SYMTAB_SHNDX` section linked to this section, its data is used for the extended
+    /// section indices. The linked section is found with the position of the section header in the
+    /// section header table, so section headers, which are not borrowed from the ELF file, have no
+    /// extended section indices.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidClass] - The provided ELF file's class is not valid
+    /// - [Error::InvalidSectionIndex] - The linked string table is not in the section header table
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    ///
+    /// ## See also
+    /// - [SymbolTable::from_section_index] for a section with the index in the section header table
+    pub fn from_section(elf: &Elf<'a>, section_header: &SectionHeader) -> Result<Self, Error> {
+        let section_index = elf
+            .section_headers
+            .iter()
+            .flatten()
+            .position(|header| ptr::eq(header, section_header));
+        Self::read_section(elf, section_header, section_index)
+    }
+
+    /// This function creates a symbol table view over the data of the section with the specified
+    /// index in the section header table. If there is a `SHT_SYMTAB_SHNDX` section linked to this
+    /// section index, its data is used for the extended section indices.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::InvalidSectionIndex] - The section is not in the section header table
+    /// - For errors while reading the symbol table, see [SymbolTable::from_section]
+    pub fn from_section_index(elf: &Elf<'a>, section_index: usize) -> Result<Self, Error> {
+        Self::read_section(elf, elf.section_header(section_index)?, Some(section_index))
+    }
+
+    /// This function creates a symbol table view over the data of the specified section with the
+    /// extended section indices of the `SHT_SYMTAB_SHNDX` section linked to the section index.
+    fn read_section(
+        elf: &Elf<'a>, section_header: &SectionHeader, section_index: Option<usize>,
+    ) -> Result<Self, Error> {
+        let extended_indices = match elf.section_headers.iter().flatten().find(|header| {
+            header.ty == SectionType::SymbolTableIndex && Some(header.link as usize) == section_index
+        }) {
+            Some(header) => header.data(elf)?,
+            None => &[],
+        };
+
+        Ok(Self::new(
+            elf.header.ident,
+            section_header.data(elf)?,
+            section_header.entry_size as usize,
+            elf.string_table(section_header.link as usize)?,
+        )?
+        .with_extended_indices(extended_indices))
+    }
+
+    /// This function returns the count of symbols in the symbol table.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / self.entry_size
+    }
+
+    /// This function returns whether the symbol table has no symbols.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// This function returns the string table, which is used to resolve the names of the symbols.
+    #[inline]
+    pub const fn strings(&self) -> StringTable<'a> {
+        self.strings
+    }
+
+    /// This function reads the symbol at the specified index in the symbol table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the symbol table
+    pub fn get(&self, index: usize) -> Result<Symbol, Error> {
+        Symbol::read(
+            &self.ident,
+            self.bytes,
+            index.saturating_mul(self.entry_size),
+        )
+    }
+
+    /// This function returns the section index of the specified symbol, which has the specified
+    /// index in this table. If the symbol has the [Symbol::EXTENDED_SECTION] index, the real index
+    /// is read from the `SHT_SYMTAB_SHNDX` section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The table has no extended section indices
+    /// - [Error::UnexpectedEnd] - The index is not in the extended section indices
+    pub fn section_index(&self, index: usize, symbol: &Symbol) -> Result<u32, Error> {
+        if symbol.section_index != Symbol::EXTENDED_SECTION {
+            return Ok(symbol.section_index as u32);
+        }
+        if self.extended_indices.is_empty() {
+            return Err(Error::MissingSection(".symtab_shndx"));
+        }
+        self.ident
+            .endian
+            .read(self.extended_indices, Some(&mut index.saturating_mul(4)))
+    }
+
+    /// This function resolves the name of the specified symbol with the linked string table.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The name is not in the string table
+    /// - [Error::InvalidString] - The name is not valid UTF-8
+    #[inline]
+    pub fn name(&self, symbol: &Symbol) -> Result<&'a str, Error> {
+        self.strings.get(symbol.name as usize)
+    }
+
+    /// This function returns an iterator over all symbols with their names in the symbol table.
+    pub fn iter(&self) -> impl Iterator<Item = Result<(&'a str, Symbol), Error>> + 'a {
+        let table = *self;
+        (0..self.len()).map(move |index| {
+            let symbol = table.get(index)?;
+            Ok((table.name(&symbol)?, symbol))
+        })
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns a view over the first section with the specified type.
+    fn symbol_table_by_type(
+        &self, ty: SectionType, name: &'static str,
+    ) -> Result<SymbolTable<'a>, Error> {
+        let section_header = self
+            .section_headers
+            .iter()
+            .flatten()
+            .find(|section_header| section_header.ty == ty)
+            .ok_or(Error::MissingSection(name))?;
+        SymbolTable::from_section(self, section_header)
+    }
+
+    /// This function returns a view over the symbol table (`.symtab`) of the ELF file.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no symbol table
+    /// - [Error::InvalidSectionIndex] - The linked string table is not in the section header table
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    pub fn symbol_table(&self) -> Result<SymbolTable<'a>, Error> {
+        self.symbol_table_by_type(SectionType::SymbolTable, ".symtab")
+    }
+
+    /// This function returns a view over the dynamic symbol table (`.dynsym`) of the ELF file. If
+    /// the ELF file has no such section (e.g. stripped section headers), the table is read with the
+    /// `DT_SYMTAB` entry of the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no dynamic symbol table and dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_SYMTAB` entry
+    /// - [Error::InvalidSectionIndex] - The linked string table is not in the section header table
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    /// - [Error::UnmappedAddress] - The address of the table is not in a loadable segment
+    pub fn dynamic_symbol_table(&self) -> Result<SymbolTable<'a>, Error> {
+        match self.symbol_table_by_type(SectionType::DynamicSymbol, ".dynsym") {
+            Err(Error::MissingSection(_)) => self.dynamic_symbol_table_from_dynamic(),
+            result => result,
+        }
+    }
+
+    /// This function returns an iterator over all symbols with their names in the symbol table of
+    /// the ELF file. For more information, see [Elf::symbol_table].
+    #[inline]
+    pub fn symbols(&self) -> Result<impl Iterator<Item = Result<(&'a str, Symbol), Error>>, Error> {
+        Ok(self.symbol_table()?.iter())
+    }
+
+    /// This function returns an iterator over all symbols with their names in the dynamic symbol
+    /// table of the ELF file. For more information, see [Elf::dynamic_symbol_table].
+    #[inline]
+    pub fn dynamic_symbols(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<(&'a str, Symbol), Error>>, Error> {
+        Ok(self.dynamic_symbol_table()?.iter())
+    }
+}
+
+/// This structure represents the lookup index over the symbols of an ELF file. The index is built
+/// lazily on the first lookup and contains all defined symbols sorted by their name and all sized
+/// functions and objects sorted by their address with the size of the largest of them.
+#[derive(Debug, Default)]
+pub(crate) struct SymbolIndex<'a> {
+    by_name: Vec<(&'a str, Symbol)>,
+    by_address: Vec<(&'a str, Symbol)>,
+    max_size: u64,
+}
+
+impl<'a> SymbolIndex<'a> {
+    /// This function builds the index over the symbol table and the dynamic symbol table of the
+    /// specified ELF file. Symbols, which can't be read or resolved, are ignored.
+    fn build(elf: &Elf<'a>) -> Self {
+        let mut by_name = Vec::new();
+        for symbol_table in [elf.symbol_table(), elf.dynamic_symbol_table()]
+            .into_iter()
+            .flatten()
+        {
+            by_name.extend(
+                symbol_table
+                    .iter()
+                    .filter_map(Result::ok)
+                    .filter(|(name, symbol)| !name.is_empty() && !symbol.is_undefined()),
+            );
+        }
+
+        // Build address index over all functions and objects with a size
+        let mut by_address = by_name
+            .iter()
+            .filter(|(_, symbol)| {
+                symbol.size > 0
+                    && matches!(
+                        symbol.ty,
+                        SymbolType::Function | SymbolType::GnuIndirectFunction | SymbolType::Object
+                    )
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        by_address.sort_unstable_by_key(|(_, symbol)| symbol.value);
+        by_name.sort_unstable_by_key(|(name, _)| *name);
+        let max_size = by_address
+            .iter()
+            .map(|(_, symbol)| symbol.size)
+            .max()
+            .unwrap_or(0);
+        Self {
+            by_name,
+            by_address,
+            max_size,
+        }
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns the lookup index over the symbols and builds it, if it doesn't exist.
+    #[inline]
+    fn symbol_index(&self) -> &SymbolIndex<'a> {
+        self.symbol_index.call_once(|| SymbolIndex::build(self))
+    }
+
+    /// This function looks up the defined symbol with the specified name in the symbol table and
+    /// the dynamic symbol table. The lookup index is built on the first lookup, so all following
+    /// lookups are done with a binary search.
+    pub fn find_symbol(&self, name: &str) -> Option<&Symbol> {
+        let by_name = &self.symbol_index().by_name;
+        by_name
+            .binary_search_by_key(&name, |(name, _)| *name)
+            .ok()
+            .map(|index| &by_name[index].1)
+    }
+
+    /// This function looks up the function or object, which encloses the specified virtual
+    /// address. If multiple symbols enclose the address (like an object nested in a function), the
+    /// symbol with the nearest start is returned. If a symbol is found, this function returns the
+    /// name of the symbol, the symbol and the offset of the address from the start of the symbol.
+    /// The lookup index is built on the first lookup, so all following lookups are done with a
+    /// binary search.
+    pub fn symbolize(&self, address: u64) -> Option<(&'a str, &Symbol, u64)> {
+        let index = self.symbol_index();
+        let by_address = &index.by_address;
+        let end = by_address.partition_point(|(_, symbol)| symbol.value <= address);
+
+        // Walk back over all symbols, which start close enough to enclose the address, and return
+        // the first enclosing one
+        by_address[..end]
+            .iter()
+            .rev()
+            .take_while(|(_, symbol)| address - symbol.value < index.max_size)
+            .find(|(_, symbol)| address - symbol.value < symbol.size)
+            .map(|(name, symbol)| (*name, symbol, address - symbol.value))
+    }
+}
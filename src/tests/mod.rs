@@ -1,4 +1,20 @@
 use crate::{
+    compression::{
+        CompressionHeader,
+        CompressionType,
+    },
+    debug::crc32,
+    dynamic::{
+        DynamicEntry,
+        DynamicFlags1,
+    },
+    group::GroupFlags,
+    hash::{
+        elf_hash,
+        gnu_hash,
+        GnuHashTable,
+        SysvHashTable,
+    },
     header::{
         ident::{
             ElfClass,
@@ -6,6 +22,7 @@ use crate::{
             ElfOsABI,
             ElfVersion,
         },
+        FileHeader,
         FileType,
         SectionFlags,
         SectionType,
@@ -13,9 +30,131 @@ use crate::{
         SegmentType,
         TargetMachine,
     },
+    note::{
+        property::{
+            AArch64Features,
+            GnuProperty,
+            X86Features,
+            X86IsaLevel,
+        },
+        GnuAbiTag,
+        Note,
+        NoteIter,
+        NoteKind,
+    },
+    relocation::{
+        x86_64::X86_64RelocationType,
+        RelocationKind,
+    },
+    security::Relro,
+    std::cmp::Ordering,
+    string_table::StringTable,
+    symbol::{
+        Symbol,
+        SymbolBinding,
+        SymbolTable,
+        SymbolType,
+        SymbolVisibility,
+    },
+    version::{
+        compare_versions,
+        split_version,
+        SymbolVersion,
+    },
     Elf,
+    Error,
+};
+use alloc::borrow::Cow;
+
+#[cfg(feature = "std")]
+use crate::{
+    debug::DebugFileResolver,
+    file::ElfFile,
+    reader::ElfReader,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::ToString,
+    vec,
+    vec::Vec,
 };
 
+/// This structure describes a section of the ELF files, which are built by [build_relocatable].
+#[derive(Default)]
+struct TestSection<'a> {
+    name: &'a str,
+    ty: u32,
+    flags: u64,
+    link: u32,
+    info: u32,
+    entry_size: u64,
+    data: &'a [u8],
+}
+
+/// This function builds a 64-bit little-endian relocatable ELF file with the specified sections
+/// after the null section. The section header string table is appended as last section.
+fn build_relocatable(sections: &[TestSection]) -> Vec<u8> {
+    let shstrtab = TestSection {
+        name: ".shstrtab",
+        ty: 3,
+        ..Default::default()
+    };
+    let mut names = vec![0];
+    let mut name_offsets = Vec::new();
+    for section in sections.iter().chain([&shstrtab]) {
+        name_offsets.push(names.len() as u32);
+        names.extend(section.name.as_bytes());
+        names.push(0);
+    }
+
+    // Write the data of the sections after the file header
+    let mut bytes = vec![0; 64];
+    let mut data_offsets = Vec::new();
+    for data in sections
+        .iter()
+        .map(|section| section.data)
+        .chain([names.as_slice()])
+    {
+        bytes.resize(bytes.len().next_multiple_of(8), 0);
+        data_offsets.push(bytes.len() as u64);
+        bytes.extend(data);
+    }
+
+    // Write the section header table
+    bytes.resize(bytes.len().next_multiple_of(8), 0);
+    let section_header_offset = bytes.len() as u64;
+    bytes.extend([0; 64]);
+    for (index, section) in sections.iter().chain([&shstrtab]).enumerate() {
+        let size = if index < sections.len() {
+            section.data.len()
+        } else {
+            names.len()
+        };
+        bytes.extend(name_offsets[index].to_le_bytes());
+        bytes.extend(section.ty.to_le_bytes());
+        bytes.extend(section.flags.to_le_bytes());
+        bytes.extend(0_u64.to_le_bytes());
+        bytes.extend(data_offsets[index].to_le_bytes());
+        bytes.extend((size as u64).to_le_bytes());
+        bytes.extend(section.link.to_le_bytes());
+        bytes.extend(section.info.to_le_bytes());
+        bytes.extend(1_u64.to_le_bytes());
+        bytes.extend(section.entry_size.to_le_bytes());
+    }
+
+    // Write the file header
+    let section_count = sections.len() as u16 + 2;
+    bytes[..8].copy_from_slice(&[0x7F, b'E', b'L', b'F', 2, 1, 1, 0]);
+    bytes[16..24].copy_from_slice(&[1, 0, 62, 0, 1, 0, 0, 0]);
+    bytes[40..48].copy_from_slice(&section_header_offset.to_le_bytes());
+    bytes[52..54].copy_from_slice(&64_u16.to_le_bytes());
+    bytes[58..60].copy_from_slice(&64_u16.to_le_bytes());
+    bytes[60..62].copy_from_slice(&section_count.to_le_bytes());
+    bytes[62..64].copy_from_slice(&(section_count - 1).to_le_bytes());
+    bytes
+}
+
 #[test]
 fn test_file_header() {
     let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
@@ -278,3 +417,1501 @@ fn test_program_headers() {
     assert_eq!(program_header.flags, SegmentFlags::READABLE);
     assert_eq!(program_header.alignment, 0x1);
 }
+
+#[test]
+fn test_truncated_file() {
+    let bytes = include_bytes!("hello-world");
+
+    // Truncated headers are reported with the structure and offset
+    assert!(matches!(
+        Elf::from_bytes(&bytes[..40]),
+        Err(Error::UnexpectedEnd("FileHeader", 4))
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&bytes[..100]),
+        Err(Error::ProgramHeaderTableOutOfBounds(64, _))
+    ));
+
+    // No truncation of the headers panics
+    for length in 0..512 {
+        let _ = Elf::from_bytes(&bytes[..length]);
+    }
+}
+
+#[test]
+fn test_section_names() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let section_headers = elf.section_headers().unwrap();
+
+    // Resolve names of single sections
+    assert_eq!(
+        elf.section_name(section_headers.get(0).unwrap()).unwrap(),
+        ""
+    );
+    assert_eq!(
+        elf.section_name(section_headers.get(1).unwrap()).unwrap(),
+        ".interp"
+    );
+    assert_eq!(
+        elf.section_name(
+            section_headers
+                .get(elf.file_header().string_table_index as usize)
+                .unwrap()
+        )
+        .unwrap(),
+        ".shstrtab"
+    );
+
+    // Lookup sections by their name
+    let text = elf.section_by_name(".text").unwrap();
+    assert_eq!(text.ty, SectionType::ProgBits);
+    assert_eq!(text.flags, SectionFlags::ALLOC | SectionFlags::INSTRUCTIONS);
+    assert!(elf.section_by_name(".does-not-exist").is_none());
+
+    // Iterate over all sections with names
+    let sections = elf.sections().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(sections.len(), section_headers.len());
+    assert!(sections.iter().any(|(name, _)| *name == ".dynsym"));
+}
+
+#[test]
+fn test_symbols() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+
+    // Check the first null symbol of the symbol table
+    let symbol_table = elf.symbol_table().unwrap();
+    assert!(!symbol_table.is_empty());
+    let symbol = symbol_table.get(0).unwrap();
+    assert_eq!(symbol, Symbol::default());
+    assert_eq!(symbol_table.name(&symbol).unwrap(), "");
+
+    // Check the main function
+    let (_, main) = elf
+        .symbols()
+        .unwrap()
+        .map(Result::unwrap)
+        .find(|(name, _)| *name == "main")
+        .unwrap();
+    assert_eq!(main.ty, SymbolType::Function);
+    assert_eq!(main.binding, SymbolBinding::Global);
+    assert_eq!(main.visibility, SymbolVisibility::Default);
+    assert!(!main.is_undefined());
+    assert_eq!(
+        elf.section_name(elf.section_header(main.section_index as usize).unwrap())
+            .unwrap(),
+        ".text"
+    );
+
+    // Check an undefined dynamic symbol
+    let (_, write) = elf
+        .dynamic_symbols()
+        .unwrap()
+        .map(Result::unwrap)
+        .find(|(name, _)| *name == "write")
+        .unwrap();
+    assert_eq!(write.ty, SymbolType::Function);
+    assert!(write.is_undefined());
+}
+
+#[test]
+fn test_symbol_lookup() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+
+    // Lookup by name
+    let main = *elf.find_symbol("main").unwrap();
+    assert_eq!(main.ty, SymbolType::Function);
+    assert!(elf.find_symbol("does_not_exist").is_none());
+
+    // Lookup by address
+    let (name, symbol, offset) = elf.symbolize(main.value + 0x4).unwrap();
+    assert_eq!(name, "main");
+    assert_eq!(*symbol, main);
+    assert_eq!(offset, 0x4);
+    assert!(elf
+        .symbolize(main.value + main.size)
+        .map_or(true, |(name, ..)| name != "main"));
+    assert!(elf.symbolize(0x0).is_none());
+
+    // The ELF file with the lazy lookup index can be shared between threads
+    fn assert_sync<T: Sync>(_: &T) {}
+    assert_sync(&elf);
+}
+
+#[test]
+fn test_symbolize_nested() {
+    // The function `outer` contains the object `inner`, the smaller function `helper` and the label
+    // `label` without size
+    let mut symbols = vec![0; 24];
+    for (name, info, value, size) in [
+        (1_u32, 0x12, 0x1000_u64, 0x100_u64),
+        (7, 0x01, 0x1010, 0x10),
+        (13, 0x12, 0x1040, 0x20),
+        (20, 0x00, 0x1070, 0),
+    ] {
+        symbols.extend(name.to_le_bytes());
+        symbols.extend([info, 0]);
+        symbols.extend(1_u16.to_le_bytes());
+        symbols.extend(value.to_le_bytes());
+        symbols.extend(size.to_le_bytes());
+    }
+    let bytes = build_relocatable(&[
+        TestSection {
+            name: ".text",
+            ty: 1,
+            flags: 0x6,
+            data: &[0xC3],
+            ..Default::default()
+        },
+        TestSection {
+            name: ".strtab",
+            ty: 3,
+            data: b"\0outer\0inner\0helper\0label\0",
+            ..Default::default()
+        },
+        TestSection {
+            name: ".symtab",
+            ty: 2,
+            link: 2,
+            info: 1,
+            entry_size: 24,
+            data: &symbols,
+            ..Default::default()
+        },
+    ]);
+
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    let symbolize = |address| {
+        elf.symbolize(address)
+            .map(|(name, _, offset)| (name, offset))
+    };
+    assert_eq!(symbolize(0x1014), Some(("inner", 0x4)));
+    assert_eq!(symbolize(0x1020), Some(("outer", 0x20)));
+    assert_eq!(symbolize(0x1048), Some(("helper", 0x8)));
+    assert_eq!(symbolize(0x1080), Some(("outer", 0x80)));
+    assert_eq!(symbolize(0x1100), None);
+    assert_eq!(symbolize(0xFFF), None);
+}
+
+#[test]
+fn test_relocations() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let (_, table) = elf
+        .relocation_tables()
+        .map(Result::unwrap)
+        .find(|(name, _)| *name == ".rela.dyn")
+        .unwrap();
+    assert!(table.with_addend());
+    assert!(!table.is_empty());
+
+    // Relocations of executables and shared objects have no target section but reference the
+    // dynamic symbol table
+    assert!(table.target_section(&elf).is_err());
+    let symbol_table = table.symbol_table(&elf).unwrap();
+    assert_eq!(
+        symbol_table.len(),
+        elf.dynamic_symbol_table().unwrap().len()
+    );
+
+    // Check relative relocation (R_X86_64_RELATIVE) and symbol relocations
+    let relocations = table.iter().collect::<Result<Vec<_>, _>>().unwrap();
+    assert!(relocations
+        .iter()
+        .any(|relocation| relocation.ty == 8 && relocation.symbol == 0));
+    assert!(relocations
+        .iter()
+        .filter(|relocation| relocation.symbol != 0)
+        .all(|relocation| symbol_table.get(relocation.symbol as usize).is_ok()));
+}
+
+#[test]
+fn test_relocation_kinds() {
+    // Map raw types of the supported target machines
+    assert_eq!(
+        RelocationKind::new(TargetMachine::X86_64, 2),
+        RelocationKind::X86_64(X86_64RelocationType::R_X86_64_PC32)
+    );
+    assert_eq!(
+        RelocationKind::new(TargetMachine::ARM64, 283).to_string(),
+        "R_AARCH64_CALL26"
+    );
+    assert_eq!(
+        RelocationKind::new(TargetMachine::RISCV, 26).to_string(),
+        "R_RISCV_HI20"
+    );
+    assert_eq!(
+        RelocationKind::new(TargetMachine::ARM, 28).to_string(),
+        "R_ARM_CALL"
+    );
+    assert_eq!(
+        RelocationKind::new(TargetMachine::X86_64, 0xFFFF).to_string(),
+        "Unknown(65535)"
+    );
+
+    // Check the relocation types of the test file
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let (_, table) = elf
+        .relocation_tables()
+        .map(Result::unwrap)
+        .find(|(name, _)| *name == ".rela.dyn")
+        .unwrap();
+    assert!(table.iter().map(Result::unwrap).any(|relocation| {
+        relocation.kind(elf.file_header().machine)
+            == RelocationKind::X86_64(X86_64RelocationType::R_X86_64_RELATIVE)
+    }));
+}
+
+#[test]
+fn test_dynamic() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let entries = elf
+        .dynamic()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert!(!entries.contains(&DynamicEntry::Null));
+    assert!(entries
+        .iter()
+        .any(|entry| matches!(entry, DynamicEntry::StringTable(_))));
+    assert!(entries.iter().any(|entry| {
+        matches!(
+            entry,
+            DynamicEntry::Flags1(flags) if flags.contains(DynamicFlags1::PIE)
+        )
+    }));
+
+    // Resolve strings with the dynamic string table
+    assert!(elf.needed_libraries().unwrap().contains(&"libc.so.6"));
+    assert_eq!(elf.soname().unwrap(), None);
+}
+
+#[test]
+fn test_dynamic_without_section_headers() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+
+    // Remove the section headers, like in a stripped binary
+    let stripped = Elf {
+        section_headers: None,
+        ..Elf::from_bytes(include_bytes!("hello-world")).unwrap()
+    };
+    assert!(stripped.section_headers().is_none());
+
+    // Read dynamic symbols and strings with the dynamic section
+    assert_eq!(
+        stripped.needed_libraries().unwrap(),
+        elf.needed_libraries().unwrap()
+    );
+    let symbols = stripped
+        .dynamic_symbols()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        symbols,
+        elf.dynamic_symbols()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    );
+
+    // Read relocations with the dynamic section
+    let tables = stripped.dynamic_relocation_tables().unwrap();
+    let relocation_count = elf
+        .relocation_tables()
+        .map(|table| table.unwrap().1.len())
+        .sum::<usize>();
+    assert_eq!(
+        tables.iter().map(|table| table.len()).sum::<usize>(),
+        relocation_count
+    );
+    assert_eq!(
+        tables[0].symbol_table(&stripped).unwrap().len(),
+        symbols.len()
+    );
+}
+
+#[test]
+fn test_address_translation() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+
+    // Translate the entrypoint and translate it back
+    let entry_address = elf.file_header().entry_address.unwrap();
+    let (offset, index) = elf.vaddr_to_offset(entry_address).unwrap();
+    let segment = elf.program_headers().unwrap().get(index).unwrap();
+    assert_eq!(segment.ty, SegmentType::Load);
+    assert!(segment.flags.contains(SegmentFlags::EXECUTABLE));
+    assert_eq!(elf.offset_to_vaddr(offset).unwrap(), (entry_address, index));
+
+    // Read the interpreter path with the virtual address
+    let interp = elf.section_by_name(".interp").unwrap();
+    assert_eq!(
+        elf.read_at_vaddr(interp.addr, interp.size as usize)
+            .unwrap(),
+        interp.data(&elf).unwrap()
+    );
+
+    // Check the zero-filled part of the writable segment
+    let (index, segment) = elf
+        .program_headers()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .find(|(_, segment)| segment.memory_size > segment.file_size)
+        .unwrap();
+    let bss_address = segment.virtual_address + segment.file_size;
+    assert_eq!(elf.segment_at_vaddr(bss_address).unwrap().0, index);
+    assert!(matches!(
+        elf.vaddr_to_offset(bss_address),
+        Err(Error::ZeroFilledAddress(address)) if address == bss_address
+    ));
+    assert!(matches!(
+        elf.read_at_vaddr(bss_address - 4, 8),
+        Err(Error::ZeroFilledAddress(address)) if address == bss_address
+    ));
+    assert!(matches!(
+        elf.vaddr_to_offset(u64::MAX),
+        Err(Error::UnmappedAddress(u64::MAX))
+    ));
+
+    // Segments near the end of the address space don't overflow the translation
+    let (index, segment) = elf
+        .program_headers()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .find(|(_, segment)| segment.ty == SegmentType::Load)
+        .unwrap();
+    let header_offset = elf.file_header().program_header_offset as usize + index * 56;
+    let mut bytes = include_bytes!("hello-world").to_vec();
+    bytes[(header_offset + 8)..(header_offset + 16)]
+        .copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+    let hostile = Elf::from_bytes(&bytes).unwrap();
+    assert!(matches!(
+        hostile.vaddr_to_offset(segment.virtual_address + 0x20),
+        Err(Error::UnmappedAddress(_))
+    ));
+    assert!(matches!(
+        hostile.read_at_vaddr(segment.virtual_address + 0x20, 4),
+        Err(Error::UnmappedAddress(_))
+    ));
+    drop(hostile);
+
+    let address = u64::MAX - 0x10;
+    bytes[(header_offset + 8)..(header_offset + 16)].copy_from_slice(&segment.offset.to_le_bytes());
+    bytes[(header_offset + 16)..(header_offset + 24)].copy_from_slice(&address.to_le_bytes());
+    let hostile = Elf::from_bytes(&bytes).unwrap();
+    assert!(matches!(
+        hostile.offset_to_vaddr(segment.offset + 0x20),
+        Err(Error::UnmappedOffset(_))
+    ));
+    assert!(matches!(
+        hostile.read_at_vaddr(address, segment.memory_size as usize + 1),
+        Err(Error::UnmappedAddress(u64::MAX))
+    ));
+}
+
+#[test]
+fn test_notes() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let kinds = elf
+        .notes()
+        .unwrap()
+        .iter()
+        .map(|note| note.kind().unwrap())
+        .collect::<Vec<_>>();
+
+    // Check decoded notes of the segments
+    assert!(kinds
+        .iter()
+        .any(|kind| matches!(kind, NoteKind::GnuBuildId(build_id) if build_id.len() == 20)));
+    assert!(kinds.iter().any(|kind| {
+        matches!(
+            kind,
+            NoteKind::GnuAbiTag(GnuAbiTag {
+                os: 0,
+                major: 3,
+                ..
+            })
+        )
+    }));
+    assert!(kinds
+        .iter()
+        .any(|kind| matches!(kind, NoteKind::GnuProperty(_))));
+
+    // Check notes of the property section with 8-byte alignment
+    let section_header = elf.section_by_name(".note.gnu.property").unwrap();
+    let notes = NoteIter::from_section(&elf, section_header)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].name, "GNU");
+    assert_eq!(notes[0].ty, Note::GNU_PROPERTY_TYPE_0);
+}
+
+#[test]
+fn test_gnu_properties() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let properties = elf.gnu_properties().unwrap();
+    assert!(properties.iter().any(|property| {
+        matches!(property, GnuProperty::X86IsaNeeded(level) if level.contains(X86IsaLevel::BASELINE))
+    }));
+
+    // Check 64-bit little-endian properties with 8-byte padding
+    let mut ident = elf.file_header().ident;
+    #[rustfmt::skip]
+    let desc = [
+        0x02, 0x00, 0x00, 0xC0, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let properties = GnuProperty::read_all(&ident, TargetMachine::X86_64, &desc).unwrap();
+    assert_eq!(
+        properties,
+        [
+            GnuProperty::X86Features(X86Features::IBT | X86Features::SHSTK),
+            GnuProperty::StackSize(0x100000)
+        ]
+    );
+
+    // Check 32-bit big-endian properties with 4-byte padding
+    ident.class = ElfClass::Class32;
+    ident.endian = ElfEndian::Big;
+    #[rustfmt::skip]
+    let desc = [
+        0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let properties = GnuProperty::read_all(&ident, TargetMachine::ARM64, &desc).unwrap();
+    assert_eq!(
+        properties,
+        [
+            GnuProperty::AArch64Features(AArch64Features::PAC),
+            GnuProperty::StackSize(0x10000),
+            GnuProperty::NoCopyOnProtected
+        ]
+    );
+
+    // The same type is decoded as unknown on other machines
+    let properties = GnuProperty::read_all(&ident, TargetMachine::RISCV, &desc[..12]).unwrap();
+    assert_eq!(properties, [GnuProperty::Unknown(0xC0000000, &desc[8..12])]);
+    assert!(matches!(
+        GnuProperty::read_all(&ident, TargetMachine::ARM64, &desc[..10]),
+        Err(Error::UnexpectedEnd("GnuProperty", 8))
+    ));
+
+    // Check 32-bit little-endian x86 properties of i386 files
+    ident.endian = ElfEndian::Little;
+    #[rustfmt::skip]
+    let desc = [
+        0x02, 0x00, 0x00, 0xC0, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x02, 0x80, 0x00, 0xC0, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x01, 0xC0, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+    assert_eq!(TargetMachine::from(3), TargetMachine::X86);
+    let properties = GnuProperty::read_all(&ident, TargetMachine::X86, &desc).unwrap();
+    assert_eq!(
+        properties,
+        [
+            GnuProperty::X86Features(X86Features::IBT | X86Features::SHSTK),
+            GnuProperty::X86IsaNeeded(X86IsaLevel::BASELINE),
+            GnuProperty::X86IsaUsed(X86IsaLevel::BASELINE | X86IsaLevel::V2)
+        ]
+    );
+}
+
+#[test]
+fn test_security_report() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let report = elf.security_report().unwrap();
+    assert!(report.nx_stack);
+    assert_eq!(report.relro, Relro::Full);
+    assert!(report.pie);
+    assert_eq!(report.rpath, None);
+    assert_eq!(report.runpath, None);
+    assert!(report.writable_executable_segments.is_empty());
+    assert!(!report.stack_canary);
+    assert!(report.fortified_functions.is_empty());
+
+    // Make the stack executable and remove the RELRO segment
+    let program_headers = elf.program_headers().unwrap();
+    let stack_index = program_headers
+        .iter()
+        .position(|program_header| program_header.ty == SegmentType::GNUStack)
+        .unwrap();
+    let relro_index = program_headers
+        .iter()
+        .position(|program_header| program_header.ty == SegmentType::GNURelro)
+        .unwrap();
+    let table_offset = elf.file_header().program_header_offset as usize;
+    let mut bytes = include_bytes!("hello-world").to_vec();
+    bytes[table_offset + stack_index * 56 + 4] = 0x7;
+    bytes[table_offset + relro_index * 56..][..4].fill(0);
+
+    let report = Elf::from_bytes(&bytes).unwrap().security_report().unwrap();
+    assert!(!report.nx_stack);
+    assert_eq!(report.relro, Relro::None);
+    assert_eq!(report.writable_executable_segments, [stack_index]);
+
+    // Detect the canary and the fortified functions with the symbol names
+    let mut strings = vec![0];
+    let mut symbols = vec![0; 24];
+    for name in [
+        "__stack_chk_fail",
+        "__printf_chk",
+        "__memcpy_chk@GLIBC_2.3.4",
+        "__memcpy_chk",
+        "__chk",
+        "printf",
+    ] {
+        symbols.extend((strings.len() as u32).to_le_bytes());
+        symbols.extend([0x12, 0]);
+        symbols.extend([0; 18]);
+        strings.extend(name.as_bytes());
+        strings.push(0);
+    }
+    let bytes = build_relocatable(&[
+        TestSection {
+            name: ".strtab",
+            ty: 3,
+            data: &strings,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".symtab",
+            ty: 2,
+            link: 1,
+            info: 1,
+            entry_size: 24,
+            data: &symbols,
+            ..Default::default()
+        },
+    ]);
+    let report = Elf::from_bytes(&bytes).unwrap().security_report().unwrap();
+    assert!(report.stack_canary);
+    assert_eq!(report.fortified_functions, ["__memcpy_chk", "__printf_chk"]);
+    assert!(!report.nx_stack);
+    assert_eq!(report.relro, Relro::None);
+    assert!(!report.pie);
+}
+
+#[test]
+fn test_sysv_hash_table() {
+    assert_eq!(elf_hash(b""), 0);
+    assert_eq!(elf_hash(b"printf"), 0x077905A6);
+    assert_eq!(elf_hash(b"__libc_start_main"), 0x0177FF8E);
+
+    // The test file has only a GNU hash table
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    assert!(matches!(
+        elf.hash_table(),
+        Err(Error::MissingDynamicEntry("DT_HASH"))
+    ));
+
+    // Build a hash table over the dynamic symbols
+    let symbols = elf.dynamic_symbol_table().unwrap();
+    let mut buckets = [0_u32; 7];
+    let mut chains = vec![0_u32; symbols.len()];
+    for (index, symbol) in symbols.iter().enumerate().skip(1) {
+        let bucket = elf_hash(symbol.unwrap().0.as_bytes()) as usize % buckets.len();
+        chains[index] = buckets[bucket];
+        buckets[bucket] = index as u32;
+    }
+    let bytes = [buckets.len() as u32, chains.len() as u32]
+        .iter()
+        .chain(&buckets)
+        .chain(&chains)
+        .flat_map(|word| word.to_le_bytes())
+        .collect::<Vec<_>>();
+    let hash_table = SysvHashTable::new(elf.file_header().ident, &bytes).unwrap();
+    assert_eq!(hash_table.bucket_count(), 7);
+    assert_eq!(hash_table.chain_count(), symbols.len());
+
+    // Look up all symbols with the hash table
+    for (index, symbol) in symbols.iter().enumerate().skip(1) {
+        let (name, symbol) = symbol.unwrap();
+        assert_eq!(
+            hash_table.lookup(&symbols, name).unwrap(),
+            Some((index, symbol))
+        );
+    }
+    assert_eq!(hash_table.lookup(&symbols, "missing").unwrap(), None);
+    assert!(matches!(
+        SysvHashTable::new(elf.file_header().ident, &bytes[..bytes.len() - 1]),
+        Err(Error::UnexpectedEnd("SysvHashTable", _))
+    ));
+}
+
+#[test]
+fn test_gnu_hash_table() {
+    assert_eq!(gnu_hash(b""), 5381);
+    assert_eq!(gnu_hash(b"printf"), 0x156B2BB8);
+
+    // Look up the hashed symbols of the test file
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let hash_table = elf.gnu_hash_table().unwrap();
+    let symbols = elf.dynamic_symbol_table().unwrap();
+    assert_eq!(hash_table.symbol_count().unwrap(), symbols.len());
+    for index in hash_table.symbol_offset() as usize..symbols.len() {
+        let symbol = symbols.get(index).unwrap();
+        let name = symbols.name(&symbol).unwrap();
+        assert_eq!(
+            elf.lookup_dynamic_symbol(name).unwrap(),
+            Some((index, symbol))
+        );
+    }
+    assert_eq!(elf.lookup_dynamic_symbol("missing").unwrap(), None);
+
+    // Read the hash table with the dynamic section
+    let stripped = Elf {
+        section_headers: None,
+        ..Elf::from_bytes(include_bytes!("hello-world")).unwrap()
+    };
+    assert_eq!(
+        stripped.gnu_hash_table().unwrap().symbol_count().unwrap(),
+        symbols.len()
+    );
+    assert_eq!(
+        stripped.dynamic_symbol_table().unwrap().len(),
+        symbols.len()
+    );
+
+    // Build a 32-bit big-endian table with two symbols in one bucket
+    let mut ident = elf.file_header().ident;
+    ident.class = ElfClass::Class32;
+    ident.endian = ElfEndian::Big;
+    let mut symbol_bytes = [0; 48];
+    symbol_bytes[16..20].copy_from_slice(&1_u32.to_be_bytes());
+    symbol_bytes[32..36].copy_from_slice(&5_u32.to_be_bytes());
+    let symbols =
+        SymbolTable::new(ident, &symbol_bytes, 0, StringTable::new(b"\0foo\0bar\0")).unwrap();
+
+    let (foo, bar) = (gnu_hash(b"foo"), gnu_hash(b"bar"));
+    let bloom = [foo, foo >> 5, bar, bar >> 5]
+        .iter()
+        .fold(0_u32, |bloom, hash| bloom | (1 << (hash % 32)));
+    let bytes = [1, 1, 1, 5, bloom, 1, foo & !1, bar | 1]
+        .iter()
+        .flat_map(|word: &u32| word.to_be_bytes())
+        .collect::<Vec<_>>();
+    let hash_table = GnuHashTable::new(ident, &bytes).unwrap();
+    assert_eq!(hash_table.bloom_size(), 1);
+    assert_eq!(hash_table.bucket_count(), 1);
+    assert!(hash_table.may_contain(foo).unwrap());
+    assert_eq!(hash_table.symbol_count().unwrap(), 3);
+    assert_eq!(hash_table.lookup(&symbols, "foo").unwrap().unwrap().0, 1);
+    assert_eq!(hash_table.lookup(&symbols, "bar").unwrap().unwrap().0, 2);
+    assert_eq!(hash_table.lookup(&symbols, "baz").unwrap(), None);
+
+    // A bloom filter shift outside of the hash width is rejected
+    let mut hostile = bytes.clone();
+    hostile[12..16].copy_from_slice(&32_u32.to_be_bytes());
+    assert!(matches!(
+        GnuHashTable::new(ident, &hostile),
+        Err(Error::InvalidBloomShift(32))
+    ));
+}
+
+#[test]
+fn test_symbol_versions() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let required_versions = elf.required_versions().unwrap();
+    assert_eq!(required_versions.len(), 3);
+    assert!(required_versions.contains(&("ld-linux-x86-64.so.2", vec!["GLIBC_2.3"])));
+    assert!(required_versions.contains(&("libgcc_s.so.1", vec!["GCC_4.2.0", "GCC_3.0", "GCC_3.3"])));
+    assert!(matches!(
+        elf.version_definitions(),
+        Err(Error::MissingDynamicEntry("DT_VERDEF"))
+    ));
+
+    // Resolve the versions of the dynamic symbols with the tables read once
+    let symbols = elf.dynamic_symbol_table().unwrap();
+    assert_eq!(elf.version_symbol_table().unwrap().len(), symbols.len());
+    let symbol_versions = elf.symbol_versions().unwrap();
+    let versions = symbols
+        .iter()
+        .enumerate()
+        .map(|(index, symbol)| {
+            let (name, symbol) = symbol.unwrap();
+            let version = symbol_versions.get(index, &symbol).unwrap();
+            assert_eq!(version, symbol.version(&elf, index).unwrap());
+            (name, version)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(versions[0], ("", None));
+    assert!(versions.contains(&(
+        "memcpy",
+        Some(SymbolVersion {
+            name: "GLIBC_2.14",
+            file: Some("libc.so.6"),
+            hidden: false,
+        })
+    )));
+    assert!(versions.contains(&("_ITM_deregisterTMCloneTable", None)));
+
+    // Read the version tables with the dynamic section
+    let stripped = Elf {
+        section_headers: None,
+        ..Elf::from_bytes(include_bytes!("hello-world")).unwrap()
+    };
+    assert_eq!(stripped.required_versions().unwrap(), required_versions);
+    let symbol = symbols.get(4).unwrap();
+    assert_eq!(
+        symbol.version(&stripped, 4).unwrap(),
+        symbol.version(&elf, 4).unwrap()
+    );
+
+    // Files without version tables have no symbol versions
+    let bytes = build_relocatable(&[]);
+    let symbol_versions = Elf::from_bytes(&bytes).unwrap().symbol_versions().unwrap();
+    assert_eq!(symbol_versions.get(1, &Symbol::default()).unwrap(), None);
+}
+
+#[test]
+fn test_maximum_required_versions() {
+    assert_eq!(split_version("GLIBC_2.2.5"), Some(("GLIBC", "2.2.5")));
+    assert_eq!(split_version("GLIBC_PRIVATE"), None);
+    assert_eq!(compare_versions("2.14", "2.2.5"), Ordering::Greater);
+    assert_eq!(compare_versions("2.2", "2.2.5"), Ordering::Less);
+
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let maximum_versions = elf.maximum_required_versions().unwrap();
+    assert_eq!(maximum_versions.len(), 2);
+    assert_eq!(maximum_versions[0].namespace, "GCC");
+    assert_eq!(maximum_versions[0].version, "4.2.0");
+    assert_eq!(maximum_versions[0].files, ["libgcc_s.so.1"]);
+    assert_eq!(maximum_versions[0].symbols, ["_Unwind_GetIPInfo"]);
+
+    assert_eq!(maximum_versions[1].namespace, "GLIBC");
+    assert_eq!(maximum_versions[1].version, "2.34");
+    assert_eq!(maximum_versions[1].files, ["libc.so.6"]);
+    assert!(maximum_versions[1].symbols.contains(&"__libc_start_main"));
+    assert!(maximum_versions[1].is_newer_than("2.17"));
+    assert!(!maximum_versions[1].is_newer_than("2.34"));
+}
+
+#[test]
+fn test_shared_maximum_versions() {
+    let entry = |halves: &[u16], words: &[u32]| {
+        halves
+            .iter()
+            .flat_map(|half| half.to_le_bytes())
+            .chain(words.iter().flat_map(|word| word.to_le_bytes()))
+            .collect::<Vec<_>>()
+    };
+    let strings = b"\0libc.so.6\0libm.so.6\0GLIBC_2.29\0GLIBC_2.2.5\0exp\0pow\0printf\0";
+    let mut symbols = vec![0; 24];
+    for name in [44, 48, 52] {
+        symbols.extend(entry(&[], &[name]));
+        symbols.extend([0x12, 0, 0, 0]);
+        symbols.extend([0; 16]);
+    }
+    let version_symbols = entry(&[0, 4, 3, 2], &[]);
+
+    // Both libraries require GLIBC_2.29 with different indices
+    let mut requirements = entry(&[1, 2], &[1, 16, 48]);
+    requirements.extend(entry(&[0, 0, 0, 2], &[32, 16]));
+    requirements.extend(entry(&[0, 0, 0, 3], &[21, 0]));
+    requirements.extend(entry(&[1, 1], &[11, 16, 0]));
+    requirements.extend(entry(&[0, 0, 0, 4], &[21, 0]));
+    let bytes = build_relocatable(&[
+        TestSection {
+            name: ".dynstr",
+            ty: 3,
+            data: strings,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".dynsym",
+            ty: 11,
+            link: 1,
+            info: 1,
+            entry_size: 24,
+            data: &symbols,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".gnu.version",
+            ty: 0x6FFFFFFF,
+            link: 2,
+            entry_size: 2,
+            data: &version_symbols,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".gnu.version_r",
+            ty: 0x6FFFFFFE,
+            link: 1,
+            info: 2,
+            data: &requirements,
+            ..Default::default()
+        },
+    ]);
+
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    let maximum_versions = elf.maximum_required_versions().unwrap();
+    assert_eq!(maximum_versions.len(), 1);
+    assert_eq!(maximum_versions[0].namespace, "GLIBC");
+    assert_eq!(maximum_versions[0].version, "2.29");
+    assert_eq!(maximum_versions[0].files, ["libc.so.6", "libm.so.6"]);
+    assert_eq!(maximum_versions[0].symbols, ["exp", "pow"]);
+}
+
+#[test]
+fn test_section_groups() {
+    let mut symbols = [0; 48];
+    symbols[24..28].copy_from_slice(&1_u32.to_le_bytes());
+    symbols[28] = 0x12;
+    symbols[30..32].copy_from_slice(&1_u16.to_le_bytes());
+    let group = [1_u32, 1]
+        .iter()
+        .flat_map(|word| word.to_le_bytes())
+        .collect::<Vec<_>>();
+    let bytes = build_relocatable(&[
+        TestSection {
+            name: ".text.foo",
+            ty: 1,
+            flags: 0x206,
+            data: &[0xC3],
+            ..Default::default()
+        },
+        TestSection {
+            name: ".strtab",
+            ty: 3,
+            data: b"\0foo\0",
+            ..Default::default()
+        },
+        TestSection {
+            name: ".symtab",
+            ty: 2,
+            link: 2,
+            info: 1,
+            entry_size: 24,
+            data: &symbols,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".group",
+            ty: 17,
+            link: 3,
+            info: 1,
+            entry_size: 4,
+            data: &group,
+            ..Default::default()
+        },
+    ]);
+
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    assert_eq!(elf.file_header().ty, FileType::Relocatable);
+    let groups = elf.section_groups().unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].section_index, 4);
+    assert_eq!(groups[0].flags, GroupFlags::COMDAT);
+    assert!(groups[0].is_comdat());
+    assert_eq!(groups[0].signature, "foo");
+    assert_eq!(groups[0].signature_symbol.ty, SymbolType::Function);
+    assert_eq!(groups[0].members, [1]);
+    assert_eq!(
+        elf.section_name(elf.section_header(1).unwrap()).unwrap(),
+        ".text.foo"
+    );
+
+    // The test file has no section groups
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    assert!(elf.section_groups().unwrap().is_empty());
+}
+
+#[test]
+fn test_header_table_counts() {
+    // The program header table is read with the count of program headers
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let header = elf.file_header();
+    assert_ne!(header.program_header_count, header.section_header_count);
+    assert_eq!(
+        elf.program_headers().unwrap().len(),
+        header.program_header_count as usize
+    );
+
+    // Files without section headers still have all program headers
+    let mut bytes = include_bytes!("hello-world").to_vec();
+    bytes[0x28..0x30].fill(0);
+    bytes[0x3C..0x40].fill(0);
+    let stripped = Elf::from_bytes(&bytes).unwrap();
+    assert!(stripped.section_headers().is_none());
+    assert_eq!(stripped.program_headers(), elf.program_headers());
+    assert_eq!(
+        stripped.needed_libraries().unwrap(),
+        elf.needed_libraries().unwrap()
+    );
+}
+
+#[test]
+fn test_strict_identification() {
+    let bytes = build_relocatable(&[]);
+    let patch = |offset: usize, value: u8| {
+        let mut bytes = bytes.clone();
+        bytes[offset] = value;
+        bytes
+    };
+
+    // The ident bytes are parsed byte by byte with fallbacks for unknown values
+    let elf_bytes = patch(7, 0x61);
+    let elf = Elf::from_bytes(&elf_bytes).unwrap();
+    assert_eq!(elf.file_header().ident.abi, ElfOsABI::Unknown(0x61));
+    assert_eq!(ElfClass::from(7), ElfClass::Unknown(7));
+    assert_eq!(ElfEndian::from(2), ElfEndian::Big);
+    assert!(matches!(
+        Elf::from_bytes(&patch(4, 7)),
+        Err(Error::InvalidClass)
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&patch(5, 3)),
+        Err(Error::InvalidEndian)
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&patch(6, 0)),
+        Err(Error::InvalidVersion)
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&patch(9, 1)),
+        Err(Error::InvalidPadding)
+    ));
+
+    // The magic bytes must be at the start, embedded ELF files must be searched explicitly
+    let mut blob = b"firmware\x7FELF\xFF".to_vec();
+    blob.extend(&bytes);
+    assert!(matches!(Elf::from_bytes(&blob), Err(Error::InvalidMagic)));
+    let (offset, elf) = Elf::find_embedded(&blob).unwrap();
+    assert_eq!(offset, 13);
+    assert_eq!(elf.file_header().ty, FileType::Relocatable);
+    assert!(matches!(
+        Elf::find_embedded(&blob[..13]),
+        Err(Error::NotEnoughBytes(1))
+    ));
+    assert!(matches!(
+        Elf::find_embedded(b"firmware"),
+        Err(Error::InvalidMagic)
+    ));
+}
+
+#[test]
+fn test_carve_embedded() {
+    let first = build_relocatable(&[TestSection {
+        name: ".text",
+        ty: 1,
+        flags: 0x6,
+        data: &[0xC3],
+        ..Default::default()
+    }]);
+    let second = build_relocatable(&[TestSection {
+        name: ".bss",
+        ty: 8,
+        flags: 0x3,
+        data: &[],
+        ..Default::default()
+    }]);
+    let fixture = include_bytes!("hello-world");
+    assert_eq!(
+        Elf::from_bytes(fixture).unwrap().extent(),
+        fixture.len() as u64
+    );
+
+    // Build a blob with padding, a false positive and a truncated image
+    let mut blob = vec![0xFF; 100];
+    blob.extend(&first);
+    blob.extend(b"\x7FELF garbage");
+    blob.extend(&second);
+    blob.extend([0; 7]);
+    blob.extend(&first[..(first.len() - 8)]);
+
+    let images = Elf::carve_embedded(&blob);
+    assert_eq!(images.len(), 2);
+    assert_eq!((images[0].0, images[0].1), (100, first.len()));
+    assert_eq!(images[0].2.extent(), first.len() as u64);
+    let offset = 100 + first.len() + 12;
+    assert_eq!((images[1].0, images[1].1), (offset, second.len()));
+    assert_eq!(images[1].2.section_headers().unwrap().len(), 3);
+    assert!(Elf::carve_embedded(&blob[..100]).is_empty());
+}
+
+#[test]
+fn test_invalid_header_tables() {
+    let bytes = build_relocatable(&[TestSection {
+        name: ".text",
+        ty: 1,
+        flags: 0x6,
+        data: &[0xC3],
+        ..Default::default()
+    }]);
+    let patch = |patches: &[(usize, &[u8])]| {
+        let mut bytes = bytes.clone();
+        for (offset, value) in patches {
+            bytes[*offset..(offset + value.len())].copy_from_slice(value);
+        }
+        bytes
+    };
+
+    // The entry sizes must match the class of the file
+    assert!(matches!(
+        Elf::from_bytes(&patch(&[(58, &[40, 0])])),
+        Err(Error::InvalidSectionHeaderSize(40))
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&patch(&[(40, &[0; 8]), (58, &[0xFF, 0])])),
+        Err(Error::MissingSectionHeaderTable(3))
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&patch(&[(54, &[32, 0, 1, 0])])),
+        Err(Error::InvalidProgramHeaderSize(32))
+    ));
+
+    // The tables must be completely in the data
+    assert!(matches!(
+        Elf::from_bytes(&patch(&[
+            (32, &u64::MAX.to_le_bytes()),
+            (54, &[56, 0, 1, 0])
+        ])),
+        Err(Error::ProgramHeaderTableOutOfBounds(u64::MAX, 1))
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&patch(&[(60, &[100, 0])])),
+        Err(Error::SectionHeaderTableOutOfBounds(_, 100))
+    ));
+    assert!(matches!(
+        Elf::from_bytes(&bytes[..(bytes.len() - 1)]),
+        Err(Error::SectionHeaderTableOutOfBounds(_, 3))
+    ));
+
+    // The string table index must be in the section header table
+    assert!(matches!(
+        Elf::from_bytes(&patch(&[(62, &[3, 0])])),
+        Err(Error::InvalidStringTableIndex(3))
+    ));
+    assert!(Elf::from_bytes(&bytes).is_ok());
+}
+
+#[test]
+fn test_extended_numbering() {
+    let mut symbols = [0; 48];
+    symbols[24..28].copy_from_slice(&1_u32.to_le_bytes());
+    symbols[28] = 0x11;
+    symbols[30..32].copy_from_slice(&Symbol::EXTENDED_SECTION.to_le_bytes());
+    let indices = [0_u32, 1]
+        .iter()
+        .flat_map(|index| index.to_le_bytes())
+        .collect::<Vec<_>>();
+    let mut bytes = build_relocatable(&[
+        TestSection {
+            name: ".data",
+            ty: 1,
+            flags: 0x3,
+            data: &[0; 8],
+            ..Default::default()
+        },
+        TestSection {
+            name: ".strtab",
+            ty: 3,
+            data: b"\0foo\0",
+            ..Default::default()
+        },
+        TestSection {
+            name: ".symtab",
+            ty: 2,
+            link: 2,
+            info: 1,
+            entry_size: 24,
+            data: &symbols,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".symtab_shndx",
+            ty: 18,
+            link: 3,
+            entry_size: 4,
+            data: &indices,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".symtab.copy",
+            ty: 2,
+            ..Default::default()
+        },
+    ]);
+
+    // Move the counts and string table index into the first section header
+    let section_header_offset = u64::from_le_bytes(bytes[40..48].try_into().unwrap()) as usize;
+    bytes[56..58].copy_from_slice(&FileHeader::EXTENDED_COUNT.to_le_bytes());
+    bytes[60..62].copy_from_slice(&0_u16.to_le_bytes());
+    bytes[62..64].copy_from_slice(&FileHeader::EXTENDED_INDEX.to_le_bytes());
+    bytes[(section_header_offset + 32)..(section_header_offset + 40)]
+        .copy_from_slice(&7_u64.to_le_bytes());
+    bytes[(section_header_offset + 40)..(section_header_offset + 44)]
+        .copy_from_slice(&6_u32.to_le_bytes());
+
+    // Make the last symbol table identical to the symbol table with the extended indices
+    let symtab_header = section_header_offset + 3 * 64;
+    bytes.copy_within(
+        symtab_header..(symtab_header + 64),
+        section_header_offset + 5 * 64,
+    );
+
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    assert_eq!(elf.file_header().section_header_count, 0);
+    assert!(elf.program_headers().is_none());
+    assert_eq!(elf.section_headers().unwrap().len(), 7);
+    assert_eq!(elf.section_header(3).ok(), elf.section_header(5).ok());
+    assert_eq!(
+        elf.section_header(4).unwrap().ty,
+        SectionType::SymbolTableIndex
+    );
+    assert_eq!(
+        elf.section_by_name(".symtab_shndx"),
+        elf.section_header(4).ok()
+    );
+
+    // The section index of the symbol is stored in the extended section indices
+    let symbol_table = elf.symbol_table().unwrap();
+    let symbol = symbol_table.get(1).unwrap();
+    assert_eq!(symbol.section_index, Symbol::EXTENDED_SECTION);
+    assert_eq!(symbol_table.section_index(1, &symbol).unwrap(), 1);
+    assert_eq!(
+        symbol_table
+            .section_index(0, &symbol_table.get(0).unwrap())
+            .unwrap(),
+        0
+    );
+
+    // The extended indices are linked by the section index instead of the section header
+    let copy = elf.section_header(5).unwrap();
+    for symbol_table in [
+        SymbolTable::from_section(&elf, copy).unwrap(),
+        SymbolTable::from_section_index(&elf, 5).unwrap(),
+    ] {
+        assert!(matches!(
+            symbol_table.section_index(1, &symbol),
+            Err(Error::MissingSection(".symtab_shndx"))
+        ));
+    }
+    let symbol_table = SymbolTable::from_section_index(&elf, 3).unwrap();
+    assert_eq!(symbol_table.section_index(1, &symbol).unwrap(), 1);
+
+    // Tables without extended section indices can't resolve the index
+    let symbol_table = SymbolTable::new(
+        elf.file_header().ident,
+        &symbols,
+        24,
+        StringTable::new(b"\0foo\0"),
+    )
+    .unwrap();
+    assert!(matches!(
+        symbol_table.section_index(1, &symbol),
+        Err(Error::MissingSection(".symtab_shndx"))
+    ));
+}
+
+#[test]
+fn test_compressed_sections() {
+    let data = b"Hello, compressed world! ".repeat(4);
+
+    // Build a zstd frame and a zlib stream with uncompressed blocks
+    let mut zstd = vec![0x28, 0xB5, 0x2F, 0xFD, 0x20, data.len() as u8];
+    zstd.extend(&((data.len() as u32) << 3 | 1).to_le_bytes()[..3]);
+    zstd.extend(&data);
+    let mut zlib = vec![0x78, 0x01, 0x01];
+    zlib.extend((data.len() as u16).to_le_bytes());
+    zlib.extend((!(data.len() as u16)).to_le_bytes());
+    zlib.extend(&data);
+    let (a, b) = data.iter().fold((1_u32, 0_u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    zlib.extend(((b << 16) | a).to_be_bytes());
+
+    // Prepend the compression headers
+    let header = |ty: u32| {
+        let mut header = ty.to_le_bytes().to_vec();
+        header.extend([0; 4]);
+        header.extend((data.len() as u64).to_le_bytes());
+        header.extend(1_u64.to_le_bytes());
+        header
+    };
+    let zstd_section = [header(2), zstd].concat();
+    let zlib_section = [header(1), zlib.clone()].concat();
+    let mut huge_section = zstd_section.clone();
+    huge_section[8..16].copy_from_slice(&(1_u64 << 63).to_le_bytes());
+    let mut short_section = zstd_section.clone();
+    short_section[8..16].copy_from_slice(&(data.len() as u64 - 1).to_le_bytes());
+    let legacy_section = [
+        b"ZLIB".to_vec(),
+        (data.len() as u64).to_be_bytes().to_vec(),
+        zlib,
+    ]
+    .concat();
+    let bytes = build_relocatable(&[
+        TestSection {
+            name: ".debug_str",
+            ty: 1,
+            flags: 0x800,
+            data: &zstd_section,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".debug_info",
+            ty: 1,
+            flags: 0x800,
+            data: &zlib_section,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".zdebug_line",
+            ty: 1,
+            data: &legacy_section,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".debug_abbrev",
+            ty: 1,
+            data: &data,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".debug_ranges",
+            ty: 1,
+            flags: 0x800,
+            data: &huge_section,
+            ..Default::default()
+        },
+        TestSection {
+            name: ".debug_loc",
+            ty: 1,
+            flags: 0x800,
+            data: &short_section,
+            ..Default::default()
+        },
+    ]);
+    let elf = Elf::from_bytes(&bytes).unwrap();
+    let section = |index| elf.section_header(index).unwrap();
+
+    assert_eq!(
+        section(1).compression_header(&elf).unwrap(),
+        Some(CompressionHeader {
+            ty: CompressionType::Zstd,
+            size: data.len() as u64,
+            alignment: 1,
+        })
+    );
+    assert_eq!(section(4).compression_header(&elf).unwrap(), None);
+    assert!(matches!(
+        section(4).decompressed_data(&elf).unwrap(),
+        Cow::Borrowed(borrowed) if borrowed == data
+    ));
+
+    #[cfg(feature = "zstd")]
+    {
+        // The size of the header must match the decoded data without allocating it upfront
+        assert_eq!(*section(1).decompressed_data(&elf).unwrap(), data);
+        for index in [5, 6] {
+            assert!(matches!(
+                section(index).decompressed_data(&elf),
+                Err(Error::InvalidCompressedData)
+            ));
+        }
+    }
+    #[cfg(not(feature = "zstd"))]
+    assert!(matches!(
+        section(1).decompressed_data(&elf),
+        Err(Error::UnsupportedCompression(CompressionType::Zstd))
+    ));
+
+    #[cfg(feature = "zlib")]
+    {
+        assert_eq!(*section(2).decompressed_data(&elf).unwrap(), data);
+        assert_eq!(*section(3).decompressed_data(&elf).unwrap(), data);
+    }
+    #[cfg(not(feature = "zlib"))]
+    for index in [2, 3] {
+        assert!(matches!(
+            section(index).decompressed_data(&elf),
+            Err(Error::UnsupportedCompression(CompressionType::Zlib))
+        ));
+    }
+}
+
+#[test]
+fn test_debug_files() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let build_id = elf.build_id().unwrap().unwrap();
+    assert_eq!(build_id.len(), 20);
+    assert!(elf.debug_link().unwrap().is_none());
+    assert_eq!(crc32(0, b"123456789"), 0xCBF43926);
+    assert_eq!(crc32(crc32(0, b"1234"), b"56789"), 0xCBF43926);
+}
+
+/// This structure represents a unique temporary directory, which is removed when the structure is
+/// dropped (also if an assertion fails).
+#[cfg(feature = "std")]
+struct TempDir(std::path::PathBuf);
+
+#[cfg(feature = "std")]
+impl TempDir {
+    fn new(name: &str) -> Self {
+        use std::sync::atomic::{
+            AtomicUsize,
+            Ordering,
+        };
+
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos();
+        let path = std::env::temp_dir().join(format!(
+            "libelf-{}-{}-{}-{}",
+            name,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+            nanos
+        ));
+        std::fs::create_dir(&path).unwrap();
+        Self(path)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_debug_file_resolver() {
+    let elf = Elf::from_bytes(include_bytes!("hello-world")).unwrap();
+    let build_id = elf.build_id().unwrap().unwrap();
+
+    // Locate the debug file in the build ID directory of a temporary root
+    let root = TempDir::new("debug");
+    let resolver = DebugFileResolver::new(&root.0);
+    let path = resolver.build_id_path(build_id);
+    assert!(path.starts_with(root.0.join(".build-id")));
+    assert!(path.to_str().unwrap().ends_with(".debug"));
+    assert_eq!(
+        resolver
+            .resolve(&elf, std::path::Path::new("/bin/hello-world"))
+            .unwrap(),
+        None
+    );
+
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, []).unwrap();
+    assert_eq!(
+        resolver
+            .resolve(&elf, std::path::Path::new("/bin/hello-world"))
+            .unwrap(),
+        Some(path)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_elf_file() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/hello-world");
+    let bytes = include_bytes!("hello-world");
+
+    // The mapped and the read file have the same data
+    let file = ElfFile::open(path).unwrap();
+    assert!(file.is_mapped());
+    assert_eq!(file.path(), std::path::Path::new(path));
+    assert_eq!(file.bytes(), bytes);
+    let elf = file.elf().unwrap();
+    assert_eq!(
+        elf.file_header(),
+        Elf::from_bytes(bytes).unwrap().file_header()
+    );
+    assert_eq!(
+        elf.section_by_name(".text").unwrap().ty,
+        SectionType::ProgBits
+    );
+    let file = ElfFile::read(path).unwrap();
+    assert!(!file.is_mapped());
+    assert_eq!(file.bytes(), bytes);
+
+    // Missing and invalid files are reported while opening
+    assert!(matches!(
+        ElfFile::open(concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/missing")),
+        Err(Error::IO(_))
+    ));
+    assert!(matches!(
+        ElfFile::open(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")),
+        Err(Error::InvalidMagic)
+    ));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_elf_reader() {
+    let bytes = include_bytes!("hello-world");
+    let elf = Elf::from_bytes(bytes).unwrap();
+    let mut reader = ElfReader::new(std::io::Cursor::new(bytes)).unwrap();
+
+    // The headers are read on demand like the headers of the parsed file
+    assert_eq!(reader.file_header(), elf.file_header());
+    assert_eq!(
+        &reader.program_headers().unwrap(),
+        elf.program_headers().unwrap()
+    );
+    assert_eq!(
+        &reader.section_headers().unwrap(),
+        elf.section_headers().unwrap()
+    );
+    assert!(matches!(
+        reader.program_header(reader.program_header_count()),
+        Err(Error::InvalidSegmentIndex(_))
+    ));
+    assert!(matches!(
+        reader.section_header(reader.section_header_count()),
+        Err(Error::InvalidSectionIndex(_))
+    ));
+
+    // The data is read into the buffer in chunks
+    let text = elf.section_by_name(".text").unwrap();
+    assert_eq!(reader.section_name(text).unwrap(), ".text");
+    let mut buffer = vec![0; 100];
+    assert_eq!(reader.read_section_data(text, 0, &mut buffer).unwrap(), 100);
+    assert_eq!(buffer, text.data(&elf).unwrap()[..100]);
+    let length = reader
+        .read_section_data(text, text.size - 10, &mut buffer)
+        .unwrap();
+    assert_eq!(
+        buffer[..length],
+        text.data(&elf).unwrap()[(text.size as usize - 10)..]
+    );
+    let segment = reader.program_header(0).unwrap();
+    let length = reader.read_segment_data(&segment, 0, &mut buffer).unwrap();
+    assert_eq!(buffer[..length], segment.data(&elf).unwrap()[..length]);
+
+    // Truncated and invalid data is reported while creating the reader
+    assert!(matches!(
+        ElfReader::new(std::io::Cursor::new(&bytes[..100])),
+        Err(Error::ProgramHeaderTableOutOfBounds(64, _))
+    ));
+    let mut hostile = bytes.to_vec();
+    hostile[0x3A..0x3C].copy_from_slice(&0xFF_u16.to_le_bytes());
+    assert!(matches!(
+        ElfReader::new(std::io::Cursor::new(&hostile)),
+        Err(Error::InvalidSectionHeaderSize(0xFF))
+    ));
+    hostile[0x28..0x30].fill(0);
+    assert!(matches!(
+        ElfReader::new(std::io::Cursor::new(&hostile)),
+        Err(Error::MissingSectionHeaderTable(_))
+    ));
+    assert!(matches!(
+        ElfReader::new(std::io::Cursor::new(b"firmware")),
+        Err(Error::InvalidMagic)
+    ));
+    assert!(matches!(
+        ElfReader::new(std::io::Cursor::new(&bytes[..40])),
+        Err(Error::UnexpectedEnd("FileHeader", 4))
+    ));
+}
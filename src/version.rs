@@ -0,0 +1,623 @@
+use crate::{
+    dynamic::DynamicEntry,
+    header::{
+        ident::ElfIdent,
+        SectionHeader,
+        SectionType,
+    },
+    std::cmp::Ordering,
+    string_table::StringTable,
+    symbol::Symbol,
+    Elf,
+    Error,
+};
+use bitflags::bitflags;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+bitflags! {
+    /// This structure contains the flags of version definitions and required versions.
+    ///
+    /// ## See also
+    /// - [Symbol Versioning](https://refspecs.linuxfoundation.org/LSB_5.0.0/LSB-Core-generic/LSB-Core-generic/symversion.html)
+    ///   by the Linux Foundation
+    #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Default)]
+    pub struct VersionFlags: u16 {
+        /// The version definition is the version of the file itself
+        const BASE = 0x1;
+
+        /// The version is a weak version, which is not required to exist
+        const WEAK = 0x2;
+
+        /// The version is only informational
+        const INFO = 0x4;
+    }
+}
+
+/// This structure represents a single entry of the version definition section
+/// (`.gnu.version_d`). The version definitions declare the versions, which are provided by the
+/// symbols of the ELF file.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct VersionDefinition<'a> {
+    /// This field indicates the flags of the version definition.
+    pub flags: VersionFlags,
+
+    /// This field indicates the version index, which is referenced by the version symbol table.
+    pub index: u16,
+
+    /// This field indicates the ELF hash of the version name.
+    pub hash: u32,
+
+    /// This field indicates the name of the version (like `GLIBC_2.34`).
+    pub name: &'a str,
+
+    /// This field contains the names of the versions, which are inherited by this version.
+    pub parents: Vec<&'a str>,
+}
+
+/// This structure represents a single version of a library, which is required by the ELF file.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct RequiredVersion<'a> {
+    /// This field indicates the flags of the required version.
+    pub flags: VersionFlags,
+
+    /// This field indicates the version index, which is referenced by the version symbol table.
+    pub index: u16,
+
+    /// This field indicates the ELF hash of the version name.
+    pub hash: u32,
+
+    /// This field indicates the name of the version (like `GLIBC_2.34`).
+    pub name: &'a str,
+}
+
+/// This structure represents a single entry of the version requirement section
+/// (`.gnu.version_r`). The version requirement lists the versions, which are required from a
+/// single library.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct VersionRequirement<'a> {
+    /// This field indicates the file name of the library (like `libc.so.6`).
+    pub file: &'a str,
+
+    /// This field contains the versions, which are required from the library.
+    pub versions: Vec<RequiredVersion<'a>>,
+}
+
+/// This structure represents the resolved version of a symbol.
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct SymbolVersion<'a> {
+    /// This field indicates the name of the version (like `GLIBC_2.34`).
+    pub name: &'a str,
+
+    /// This field indicates the file name of the library, which provides the version. This is
+    /// None for versions defined by the ELF file itself.
+    pub file: Option<&'a str>,
+
+    /// This field indicates whether the symbol is hidden, so it is not the default version of the
+    /// symbol.
+    pub hidden: bool,
+}
+
+/// This structure represents the highest version of a version namespace (like `GLIBC`), which is
+/// required by the ELF file. The structure is created with [Elf::maximum_required_versions].
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Hash)]
+pub struct MaximumVersion<'a> {
+    /// This field indicates the namespace of the version (like `GLIBC` for `GLIBC_2.34`).
+    pub namespace: &'a str,
+
+    /// This field indicates the highest required version number (like `2.34` for `GLIBC_2.34`).
+    pub version: &'a str,
+
+    /// This field contains the file names of the libraries, which provide the version. The version
+    /// can be required from multiple libraries (like `GLIBC_2.29` from `libc.so.6` and
+    /// `libm.so.6`).
+    pub files: Vec<&'a str>,
+
+    /// This field contains the names of the dynamic symbols, which require this version.
+    pub symbols: Vec<&'a str>,
+}
+
+impl<'a> MaximumVersion<'a> {
+    /// This function returns whether the required version is newer than the specified version
+    /// number (like `2.17`). This can be used to reject ELF files, which don't run on older
+    /// systems.
+    #[inline]
+    pub fn is_newer_than(&self, version: &str) -> bool {
+        compare_versions(self.version, version) == Ordering::Greater
+    }
+}
+
+/// This function splits the specified version name into the namespace and the version number
+/// (like `GLIBC` and `2.34` for `GLIBC_2.34`). If the name has no numeric version number (like
+/// `GLIBC_PRIVATE`), this function returns None.
+pub fn split_version(name: &str) -> Option<(&str, &str)> {
+    let (namespace, version) = name.rsplit_once('_')?;
+    let numeric = version
+        .split('.')
+        .all(|part| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()));
+    (!namespace.is_empty() && numeric).then_some((namespace, version))
+}
+
+/// This function compares the specified version numbers (like `2.2.5` and `2.14`) part by part.
+/// Missing parts are less than existing parts, so `2.2` is less than `2.2.5`.
+pub fn compare_versions(first: &str, second: &str) -> Ordering {
+    fn parts(version: &str) -> impl Iterator<Item = u64> + '_ {
+        version
+            .split('.')
+            .map(|part| part.parse::<u64>().unwrap_or(u64::MAX))
+    }
+    parts(first).cmp(parts(second))
+}
+
+/// This structure represents a view over the version symbol table (`.gnu.version`) of an ELF
+/// file. The table contains the version index of every symbol in the dynamic symbol table.
+#[derive(Clone, Copy, Debug)]
+pub struct VersionSymbolTable<'a> {
+    ident: ElfIdent,
+    bytes: &'a [u8],
+}
+
+impl<'a> VersionSymbolTable<'a> {
+    /// This field contains the version index of local symbols
+    pub const LOCAL: u16 = 0x0000;
+
+    /// This field contains the version index of global symbols without version
+    pub const GLOBAL: u16 = 0x0001;
+
+    /// This field contains the bit of the version index, which marks hidden symbols
+    pub const HIDDEN: u16 = 0x8000;
+
+    /// This function creates a version symbol table view over the specified bytes of the table.
+    #[inline]
+    pub const fn new(ident: ElfIdent, bytes: &'a [u8]) -> Self {
+        Self { ident, bytes }
+    }
+
+    /// This function creates a version symbol table view over the data of the specified section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    pub fn from_section(elf: &Elf<'a>, section_header: &SectionHeader) -> Result<Self, Error> {
+        Ok(Self::new(elf.header.ident, section_header.data(elf)?))
+    }
+
+    /// This function returns the count of version indices in the table.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / 2
+    }
+
+    /// This function returns whether the table has no version indices.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// This function returns the raw version index of the symbol with the specified index in the
+    /// dynamic symbol table. The index may contain the [VersionSymbolTable::HIDDEN] bit.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the table
+    pub fn get(&self, index: usize) -> Result<u16, Error> {
+        self.ident
+            .endian
+            .read(self.bytes, Some(&mut index.saturating_mul(2)))
+    }
+}
+
+/// This structure contains the version tables of an ELF file, which are needed to resolve the
+/// versions of the dynamic symbols. The tables are read once, so the versions of all symbols can
+/// be resolved without reading the tables for every symbol.
+#[derive(Clone, Debug)]
+pub struct SymbolVersions<'a> {
+    symbols: Option<VersionSymbolTable<'a>>,
+    definitions: Vec<VersionDefinition<'a>>,
+    requirements: Vec<VersionRequirement<'a>>,
+}
+
+impl<'a> SymbolVersions<'a> {
+    /// This function resolves the version of the specified symbol, which has the specified index
+    /// in the dynamic symbol table. The version of undefined symbols is searched in the version
+    /// requirements and the version of defined symbols is searched in the version definitions. If
+    /// the ELF file has no version tables or the symbol is local or global without version, this
+    /// function returns None.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::UnexpectedEnd] - The index is not in the version symbol table
+    pub fn get(&self, index: usize, symbol: &Symbol) -> Result<Option<SymbolVersion<'a>>, Error> {
+        let Some(symbols) = &self.symbols else {
+            return Ok(None);
+        };
+        let version_index = symbols.get(index)?;
+        let hidden = version_index & VersionSymbolTable::HIDDEN != 0;
+        let version_index = version_index & !VersionSymbolTable::HIDDEN;
+        if version_index <= VersionSymbolTable::GLOBAL {
+            return Ok(None);
+        }
+
+        if symbol.is_undefined() {
+            for requirement in &self.requirements {
+                if let Some(version) = requirement
+                    .versions
+                    .iter()
+                    .find(|version| version.index == version_index)
+                {
+                    return Ok(Some(SymbolVersion {
+                        name: version.name,
+                        file: Some(requirement.file),
+                        hidden,
+                    }));
+                }
+            }
+        } else if let Some(definition) = self
+            .definitions
+            .iter()
+            .find(|definition| definition.index == version_index)
+        {
+            return Ok(Some(SymbolVersion {
+                name: definition.name,
+                file: None,
+                hidden,
+            }));
+        }
+        Ok(None)
+    }
+}
+
+impl Symbol {
+    /// This function resolves the version of this symbol, which has the specified index in the
+    /// dynamic symbol table. The version tables are read for every call, so the versions of
+    /// multiple symbols should be resolved with [Elf::symbol_versions]. For more information, see
+    /// [SymbolVersions::get].
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading the tables, see [Elf::symbol_versions]
+    /// - For errors while resolving the version, see [SymbolVersions::get]
+    #[inline]
+    pub fn version<'a>(
+        &self, elf: &Elf<'a>, index: usize,
+    ) -> Result<Option<SymbolVersion<'a>>, Error> {
+        elf.symbol_versions()?.get(index, self)
+    }
+}
+
+/// This function returns an empty list, if the ELF file has no such version table.
+fn optional<T>(result: Result<Vec<T>, Error>) -> Result<Vec<T>, Error> {
+    match result {
+        Err(Error::MissingSection(_) | Error::MissingDynamicEntry(_)) => Ok(Vec::new()),
+        result => result,
+    }
+}
+
+impl<'a> Elf<'a> {
+    /// This function returns the first section with the specified type, if it exists.
+    fn section_by_type(&self, ty: SectionType) -> Option<&SectionHeader> {
+        self.section_headers
+            .iter()
+            .flatten()
+            .find(|section_header| section_header.ty == ty)
+    }
+
+    /// This function returns the data, entry count and string table of the version definition or
+    /// requirement table. The table is read from the section with the specified type or, if there
+    /// is no such section, with the specified entries of the dynamic section.
+    fn version_table(
+        &self, ty: SectionType, name: &'static str, address: fn(DynamicEntry) -> Option<u64>,
+        count: fn(DynamicEntry) -> Option<u64>,
+    ) -> Result<(&'a [u8], usize, StringTable<'a>), Error> {
+        if let Some(section_header) = self.section_by_type(ty) {
+            return Ok((
+                section_header.data(self)?,
+                section_header.info as usize,
+                self.string_table(section_header.link as usize)?,
+            ));
+        }
+
+        let address = self
+            .find_dynamic(address)?
+            .ok_or(Error::MissingDynamicEntry(name))?;
+        let count = self.find_dynamic(count)?.unwrap_or(0);
+        Ok((
+            self.read_to_segment_end(address)?,
+            count as usize,
+            self.dynamic_string_table()?,
+        ))
+    }
+
+    /// This function returns a view over the version symbol table (`.gnu.version`) of the ELF
+    /// file. The table is read from the `SHT_GNU_versym` section or, if there is no such section,
+    /// with the `DT_VERSYM` entry of the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no version symbol section and dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_VERSYM` entry
+    /// - [Error::NotEnoughBytes] - The data of the table is not in the ELF data
+    /// - For more errors while reading the table with the dynamic section, see
+    ///   [Elf::dynamic_symbol_table] and [Elf::read_at_vaddr]
+    pub fn version_symbol_table(&self) -> Result<VersionSymbolTable<'a>, Error> {
+        if let Some(section_header) = self.section_by_type(SectionType::VersionSymbol) {
+            return VersionSymbolTable::from_section(self, section_header);
+        }
+
+        // The table has an entry for every dynamic symbol
+        let address = self
+            .find_dynamic(|entry| {
+                match entry {
+                    DynamicEntry::VersionSymbol(address) => Some(address),
+                    _ => None,
+                }
+            })?
+            .ok_or(Error::MissingDynamicEntry("DT_VERSYM"))?;
+        let count = self.dynamic_symbol_table()?.len();
+        Ok(VersionSymbolTable::new(
+            self.header.ident,
+            self.read_at_vaddr(address, count * 2)?,
+        ))
+    }
+
+    /// This function reads the version symbol table, the version definitions and the version
+    /// requirements of the ELF file, which are used to resolve the versions of the dynamic symbols.
+    /// If the ELF file has no version symbol table, no versions are resolved.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading the tables, see [Elf::version_symbol_table],
+    ///   [Elf::version_definitions] and [Elf::version_requirements]
+    pub fn symbol_versions(&self) -> Result<SymbolVersions<'a>, Error> {
+        let symbols = match self.version_symbol_table() {
+            Ok(table) => table,
+            Err(Error::MissingSection(_) | Error::MissingDynamicEntry(_)) => {
+                return Ok(SymbolVersions {
+                    symbols: None,
+                    definitions: Vec::new(),
+                    requirements: Vec::new(),
+                })
+            }
+            Err(error) => return Err(error),
+        };
+        Ok(SymbolVersions {
+            symbols: Some(symbols),
+            definitions: optional(self.version_definitions())?,
+            requirements: optional(self.version_requirements())?,
+        })
+    }
+
+    /// This function returns all version definitions of the ELF file. The definitions are read
+    /// from the `SHT_GNU_verdef` section or, if there is no such section, with the `DT_VERDEF` and
+    /// `DT_VERDEFNUM` entries of the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no version definition section and dynamic section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_VERDEF` entry
+    /// - [Error::UnexpectedEnd] - A definition or name ends outside of the table
+    /// - [Error::InvalidString] - The name of a version is not valid UTF-8
+    pub fn version_definitions(&self) -> Result<Vec<VersionDefinition<'a>>, Error> {
+        let (bytes, count, strings) = self.version_table(
+            SectionType::VersionDefinition,
+            "DT_VERDEF",
+            |entry| {
+                match entry {
+                    DynamicEntry::VersionDefinition(address) => Some(address),
+                    _ => None,
+                }
+            },
+            |entry| {
+                match entry {
+                    DynamicEntry::VersionDefinitionCount(count) => Some(count),
+                    _ => None,
+                }
+            },
+        )?;
+
+        let endian = &self.header.ident.endian;
+        let mut definitions = Vec::new();
+        let mut offset = 0_usize;
+        for _ in 0..count {
+            // Read the definition and the names of the version and its parents
+            let mut definition_offset = offset + 2;
+            let flags = endian.read::<u16>(bytes, Some(&mut definition_offset))?;
+            let index = endian.read::<u16>(bytes, Some(&mut definition_offset))?;
+            let name_count = endian.read::<u16>(bytes, Some(&mut definition_offset))?;
+            let hash = endian.read::<u32>(bytes, Some(&mut definition_offset))?;
+            let name_offset = endian.read::<u32>(bytes, Some(&mut definition_offset))? as usize;
+            let mut name_offset = offset.saturating_add(name_offset);
+            let next = endian.read::<u32>(bytes, Some(&mut definition_offset))? as usize;
+
+            let mut names = Vec::with_capacity(name_count as usize);
+            for _ in 0..name_count {
+                let mut entry_offset = name_offset;
+                let name = endian.read::<u32>(bytes, Some(&mut entry_offset))?;
+                let name_next = endian.read::<u32>(bytes, Some(&mut entry_offset))?;
+                names.push(strings.get(name as usize)?);
+                name_offset = name_offset.saturating_add(name_next as usize);
+            }
+
+            let mut names = names.into_iter();
+            definitions.push(VersionDefinition {
+                flags: VersionFlags::from_bits_retain(flags),
+                index,
+                hash,
+                name: names.next().unwrap_or(""),
+                parents: names.collect(),
+            });
+            if next == 0 {
+                break;
+            }
+            offset = offset.saturating_add(next);
+        }
+        Ok(definitions)
+    }
+
+    /// This function returns all version requirements of the ELF file. The requirements are read
+    /// from the `SHT_GNU_verneed` section or, if there is no such section, with the `DT_VERNEED`
+    /// and `DT_VERNEEDNUM` entries of the dynamic section.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - [Error::MissingSection] - The ELF file has no version requirement section and dynamic
+    ///   section
+    /// - [Error::MissingDynamicEntry] - The dynamic section has no `DT_VERNEED` entry
+    /// - [Error::UnexpectedEnd] - A requirement or name ends outside of the table
+    /// - [Error::InvalidString] - The name of a library or version is not valid UTF-8
+    pub fn version_requirements(&self) -> Result<Vec<VersionRequirement<'a>>, Error> {
+        let (bytes, count, strings) = self.version_table(
+            SectionType::VersionRequirement,
+            "DT_VERNEED",
+            |entry| {
+                match entry {
+                    DynamicEntry::VersionRequirement(address) => Some(address),
+                    _ => None,
+                }
+            },
+            |entry| {
+                match entry {
+                    DynamicEntry::VersionRequirementCount(count) => Some(count),
+                    _ => None,
+                }
+            },
+        )?;
+
+        let endian = &self.header.ident.endian;
+        let mut requirements = Vec::new();
+        let mut offset = 0_usize;
+        for _ in 0..count {
+            // Read the requirement with the library name
+            let mut requirement_offset = offset + 2;
+            let version_count = endian.read::<u16>(bytes, Some(&mut requirement_offset))?;
+            let file = endian.read::<u32>(bytes, Some(&mut requirement_offset))? as usize;
+            let version_offset = endian.read::<u32>(bytes, Some(&mut requirement_offset))? as usize;
+            let mut version_offset = offset.saturating_add(version_offset);
+            let next = endian.read::<u32>(bytes, Some(&mut requirement_offset))? as usize;
+
+            // Read the required versions of the library
+            let mut versions = Vec::with_capacity(version_count as usize);
+            for _ in 0..version_count {
+                let mut entry_offset = version_offset;
+                let hash = endian.read::<u32>(bytes, Some(&mut entry_offset))?;
+                let flags = endian.read::<u16>(bytes, Some(&mut entry_offset))?;
+                let index = endian.read::<u16>(bytes, Some(&mut entry_offset))?;
+                let name = endian.read::<u32>(bytes, Some(&mut entry_offset))?;
+                let version_next = endian.read::<u32>(bytes, Some(&mut entry_offset))?;
+                versions.push(RequiredVersion {
+                    flags: VersionFlags::from_bits_retain(flags),
+                    index,
+                    hash,
+                    name: strings.get(name as usize)?,
+                });
+                version_offset = version_offset.saturating_add(version_next as usize);
+            }
+
+            requirements.push(VersionRequirement {
+                file: strings.get(file)?,
+                versions,
+            });
+            if next == 0 {
+                break;
+            }
+            offset = offset.saturating_add(next);
+        }
+        Ok(requirements)
+    }
+
+    /// This function returns the names of the required versions grouped by the file name of the
+    /// library (like `libc.so.6` with `GLIBC_2.2.5` and `GLIBC_2.34`). For more information, see
+    /// [Elf::version_requirements].
+    pub fn required_versions(&self) -> Result<Vec<(&'a str, Vec<&'a str>)>, Error> {
+        Ok(self
+            .version_requirements()?
+            .into_iter()
+            .map(|requirement| {
+                (
+                    requirement.file,
+                    requirement
+                        .versions
+                        .iter()
+                        .map(|version| version.name)
+                        .collect(),
+                )
+            })
+            .collect())
+    }
+
+    /// This function computes the highest required version of every version namespace (like
+    /// `GLIBC` or `GCC`) with the symbols, which require these versions. The result is sorted by
+    /// the namespace. Versions without numeric version number (like `GLIBC_PRIVATE`) are ignored.
+    ///
+    /// Here is a list with all errors, which can occur while this operation:
+    /// - For errors while reading the tables, see [Elf::version_requirements],
+    ///   [Elf::version_symbol_table] and [Elf::dynamic_symbol_table]
+    pub fn maximum_required_versions(&self) -> Result<Vec<MaximumVersion<'a>>, Error> {
+        // Find the highest version and its indices of every namespace. The same version can be
+        // required from multiple libraries, so all indices of the highest version are kept.
+        let mut maximum_versions = Vec::<(MaximumVersion<'a>, Vec<u16>)>::new();
+        for requirement in self.version_requirements()? {
+            for required_version in &requirement.versions {
+                let Some((namespace, version)) = split_version(required_version.name) else {
+                    continue;
+                };
+                let index = required_version.index;
+                match maximum_versions
+                    .iter_mut()
+                    .find(|(maximum_version, _)| maximum_version.namespace == namespace)
+                {
+                    Some((maximum_version, indices)) => {
+                        match compare_versions(version, maximum_version.version) {
+                            Ordering::Greater => {
+                                maximum_version.version = version;
+                                maximum_version.files = vec![requirement.file];
+                                *indices = vec![index];
+                            }
+                            Ordering::Equal => {
+                                if !maximum_version.files.contains(&requirement.file) {
+                                    maximum_version.files.push(requirement.file);
+                                }
+                                indices.push(index);
+                            }
+                            Ordering::Less => {}
+                        }
+                    }
+                    None => {
+                        maximum_versions.push((
+                            MaximumVersion {
+                                namespace,
+                                version,
+                                files: vec![requirement.file],
+                                symbols: Vec::new(),
+                            },
+                            vec![index],
+                        ))
+                    }
+                }
+            }
+        }
+
+        // Collect the undefined symbols, which reference the highest versions
+        let version_symbols = match self.version_symbol_table() {
+            Ok(version_symbols) => Some(version_symbols),
+            Err(Error::MissingSection(_) | Error::MissingDynamicEntry(_)) => None,
+            Err(error) => return Err(error),
+        };
+        if let Some(version_symbols) = version_symbols {
+            for (index, symbol) in self.dynamic_symbol_table()?.iter().enumerate() {
+                let (name, symbol) = symbol?;
+                let version_index = version_symbols.get(index)? & !VersionSymbolTable::HIDDEN;
+                if let Some((maximum_version, _)) = maximum_versions
+                    .iter_mut()
+                    .find(|(_, indices)| indices.contains(&version_index))
+                    .filter(|_| symbol.is_undefined())
+                {
+                    maximum_version.symbols.push(name);
+                }
+            }
+        }
+
+        let mut maximum_versions = maximum_versions
+            .into_iter()
+            .map(|(maximum_version, _)| maximum_version)
+            .collect::<Vec<_>>();
+        maximum_versions.sort_unstable_by_key(|maximum_version| maximum_version.namespace);
+        Ok(maximum_versions)
+    }
+}